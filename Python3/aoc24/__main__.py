@@ -1,6 +1,7 @@
 """Project entry-point."""
 
 from argparse import ArgumentParser, Namespace
+from pathlib import Path
 
 from aoc24.solution import Solution
 
@@ -17,7 +18,7 @@ def main() -> None:
     """Project entry-point function."""
     args: Namespace = build_cli_arguments_parser().parse_args()
 
-    solution: Solution = Solution.get_from_day(args.day)(args.test)
+    solution: Solution = Solution.get_from_day(args.day)(args.test, args.input)
 
     # Part 1
     print("Part 1:", solution.solve_part_one())
@@ -34,6 +35,7 @@ def build_cli_arguments_parser() -> ArgumentParser:
     )
     parser.add_argument("day", type=int, help="Day to run")
     parser.add_argument("--test", action="store_true", help="Toggle to run on test input")
+    parser.add_argument("--input", type=Path, help="Read the input from this file instead")
 
     return parser
 
@@ -31,11 +31,10 @@ class Day17(Solution):
         program_line: str = self.file.open('r', encoding="utf-8").readlines()[4]
         self.program = list(map(int, program_line.removeprefix("Program: ").strip().split(",")))
 
-    def solve_part_one(self) -> int:
+    def solve_part_one(self) -> str:
         """Solution for part one."""
         stdout: list[int] = Computer(self.program, self.a).run()
-        print(",".join(map(str, stdout)))
-        return 1234
+        return ",".join(map(str, stdout))
 
     def solve_part_two(self) -> int:
         """Solution for part two."""
@@ -38,11 +38,11 @@ class Solution(ABC):
     test_name: ClassVar[str | None] = None
 
     @abstractmethod
-    def solve_part_one(self) -> int:
+    def solve_part_one(self) -> int | str:
         """Solution for part one."""
 
     @abstractmethod
-    def solve_part_two(self) -> int:
+    def solve_part_two(self) -> int | str:
         """Solution for part two."""
 
     def __init_subclass__(cls, *args: Any, **kwargs: Any) -> None:
@@ -50,9 +50,16 @@ class Solution(ABC):
         super().__init_subclass__(**kwargs)
         cls._solutions[cls.day] = cls
 
-    def __init__(self, is_test: bool) -> None:
+    def __init__(self, is_test: bool, file: Path | None = None) -> None:
         """Initialize a new Solution object.
 
+        Parameters
+        ----------
+        is_test : bool
+            Whether to read the test input rather than the real one.
+        file : Path | None
+            Input file to read instead, whatever `is_test`.
+
         Raises
         ------
         FileNotFoundError
@@ -61,7 +68,9 @@ class Solution(ABC):
         """
         self.file: Path
 
-        if is_test is False:
+        if file is not None:
+            self.file = file
+        elif is_test is False:
             self.file = INPUT_DATA_PATH / f"day{self.day:02d}.txt"
         else:
             name_extra: str = "" if self.test_name is None else f"-{self.test_name}"
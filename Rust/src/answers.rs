@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::aoc::{Answer, DEFAULT_EXAMPLE};
+use crate::error::AocError;
+
+pub fn answers_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("answers.toml")
+}
+
+/// Known answers, stored as strings to support both integer and text answers.
+///
+/// ```toml
+/// [day01.input]
+/// part1 = "1882714"
+/// part2 = "19437052"
+///
+/// [day01.test]
+/// part1 = "11"
+/// part2 = "31"
+///
+/// [day12.test-small]
+/// part1 = "140"
+/// ```
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// Result of the comparison of a computed answer against the stored one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CheckStatus {
+    Pass,
+    Fail(String), // Expected answer
+    Unknown,
+}
+
+/// Key of the answers of an input in a day's table, the example being `None` for the real input.
+pub fn input_key(example: Option<&str>) -> String {
+    match example {
+        None => String::from("input"),
+        Some(DEFAULT_EXAMPLE) => String::from("test"),
+        Some(name) => format!("test-{name}"),
+    }
+}
+
+impl AnswerStore {
+    /// Load the answers file, a missing file being considered empty.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content: String = fs::read_to_string(path).map_err(|error| {
+            AocError::invalid_answers(path, format!("Unreadable file ({error})"))
+        })?;
+        toml::from_str(&content).map_err(|error| AocError::invalid_answers(path, error.message()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let content: String = toml::to_string(self)
+            .map_err(|error| AocError::invalid_answers(path, error.to_string()))?;
+        fs::write(path, content)
+            .map_err(|error| AocError::invalid_answers(path, format!("Unwritable file ({error})")))
+    }
+
+    pub fn get(&self, day: usize, key: &str, part: usize) -> Option<&str> {
+        let answers: &PartAnswers = self.days.get(&day_key(day))?.get(key)?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, day: usize, key: &str, part: usize, answer: &Answer) -> CheckStatus {
+        match self.get(day, key, part) {
+            None => CheckStatus::Unknown,
+            Some(expected) if *expected == answer.to_string() => CheckStatus::Pass,
+            Some(expected) => CheckStatus::Fail(expected.to_string()),
+        }
+    }
+
+    /// Store a solved answer, overriding the previous one if any.
+    pub fn record(&mut self, day: usize, key: &str, part: usize, answer: &Answer) {
+        if !answer.is_solved() {
+            return;
+        }
+
+        let answers: &mut PartAnswers = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(key.to_string())
+            .or_default();
+        match part {
+            1 => answers.part1 = Some(answer.to_string()),
+            _ => answers.part2 = Some(answer.to_string()),
+        }
+    }
+}
+
+fn day_key(day: usize) -> String {
+    format!("day{day:0>2}")
+}
@@ -1,40 +1,309 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{Display, Formatter};
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
-pub trait Aoc24Solution {
+use crate::error::AocError;
+use crate::provider::{CachedProvider, FsCache, InputProvider};
+
+/// Answer of a puzzle part.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// The part is solved, but not for this input, e.g. an example too small for it.
+    #[default]
+    Unsolved,
+    /// The part has no solution yet.
+    NotImplemented,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved | Answer::NotImplemented)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Unsolved => write!(f, "Unsolved"),
+            Answer::NotImplemented => write!(f, "Not implemented"),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($int_type:ty),*) => {
+        $(
+            impl From<$int_type> for Answer {
+                fn from(value: $int_type) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// `None` stands for an input which the part has no answer for.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None => Answer::Unsolved,
+            Some(answer) => answer.into(),
+        }
+    }
+}
+
+// Input location
+
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+const DEFAULT_DATA_DIR: &str = "data";
+
+/// Directory holding `inputs/`, `tests/` and `answers.toml`.
+///
+/// By order of priority: `--data-dir`, `AOC_DATA_DIR`, then `./data`.
+pub fn resolve_data_dir(data_dir: Option<&str>) -> PathBuf {
+    match data_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => env::var_os(DATA_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR)),
+    }
+}
+
+/// Where a solution reads its input from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    DataDir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `--input` takes precedence over the data directory, `-` standing for stdin.
+    pub fn resolve(input: Option<&str>, data_dir: Option<&str>) -> Self {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(file) => InputSource::File(PathBuf::from(file)),
+            None => InputSource::DataDir(resolve_data_dir(data_dir)),
+        }
+    }
+}
+
+fn read_input_file(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|source| AocError::MissingInput {
+        path: path.display().to_string(),
+        source,
+    })
+}
+
+// Examples
+
+/// Name of the example read from `tests/dayNN.txt`, others being read from `tests/dayNN-<name>.txt`.
+pub const DEFAULT_EXAMPLE: &str = "example";
+
+/// Example input given in a puzzle's statement, with its expected answers when known.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Example {
+    pub name: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    pub fn new(
+        name: &'static str,
+        part_one: Option<&'static str>,
+        part_two: Option<&'static str>,
+    ) -> Self {
+        Example {
+            name,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+// Puzzle parameters
+
+/// Number which differs between the examples and the real input, such as the size of a room.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub real: i64,
+    pub test: i64,
+    pub min: i64, // Lower values are rejected, such as an empty room
+}
+
+impl Param {
+    pub fn new(name: &'static str, real: i64, test: i64) -> Self {
+        Param {
+            name,
+            real,
+            test,
+            min: i64::MIN,
+        }
+    }
+
+    /// Reject values lower than `min`.
+    pub fn at_least(mut self, min: i64) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+/// Values of the parameters of a day, resolved for a given input.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: BTreeMap<&'static str, i64>,
+}
+
+impl Params {
+    /// Take the default of each parameter for the kind of input, unless overridden.
+    ///
+    /// Overrides of parameters which the day does not declare are ignored, the last one wins. A
+    /// value below the minimum of its parameter is an error.
+    pub fn resolve(
+        declared: &[Param],
+        is_test: bool,
+        overrides: &[(String, i64)],
+    ) -> Result<Self, AocError> {
+        let values: BTreeMap<&'static str, i64> = declared
+            .iter()
+            .map(|param| {
+                let value: i64 = overrides
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name == param.name)
+                    .map(|(_, value)| *value)
+                    .unwrap_or(if is_test { param.test } else { param.real });
+                match value < param.min {
+                    true => Err(AocError::invalid_param(format!(
+                        "'{}' must be at least {}, got {value}",
+                        param.name, param.min
+                    ))),
+                    false => Ok((param.name, value)),
+                }
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(Params { values })
+    }
+
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, AocError> {
+        let value: i64 = *self
+            .values
+            .get(name)
+            .ok_or_else(|| AocError::invalid_param(format!("Undeclared parameter '{name}'")))?;
+        T::try_from(value).map_err(|_| {
+            AocError::invalid_param(format!("Value {value} is out of range for '{name}'"))
+        })
+    }
+}
+
+pub trait Aoc24Solution: Send {
     fn get_day_number(&self) -> usize;
-    fn get_extra_name(&self) -> Option<&str> {
-        None
+
+    /// Examples of the puzzle, the first one being used by `--use-test`.
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, None, None)]
+    }
+
+    /// Parameters of the puzzle, with their defaults for the real input and the examples.
+    fn get_params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Number of parts of the puzzle, the last day only having one.
+    fn get_part_count(&self) -> usize {
+        2
     }
 
     // File path building
 
-    fn build_input_path(&self) -> String {
-        let day_nbr: usize = self.get_day_number();
-        format!("data/inputs/day{day_nbr:0>2}.txt")
+    fn build_input_path(&self, data_dir: &Path) -> PathBuf {
+        FsCache::new(data_dir).path(self.get_day_number())
     }
-    fn build_test_path(&self) -> String {
+    fn build_test_path(&self, data_dir: &Path, example: &str) -> PathBuf {
         let day_nbr: usize = self.get_day_number();
-        let extra: String = match self.get_extra_name() {
-            None => String::from(""),
-            Some(val) => format!("-{val}"),
+        let extra: String = match example {
+            DEFAULT_EXAMPLE => String::from(""),
+            name => format!("-{name}"),
         };
-        format!("data/tests/day{day_nbr:0>2}{extra}.txt")
+        data_dir
+            .join("tests")
+            .join(format!("day{day_nbr:0>2}{extra}.txt"))
     }
 
-    fn get_data(&self, is_test: bool) -> String {
-        let path: String = match is_test {
-            false => self.build_input_path(),
-            true => self.build_test_path(),
+    /// Read the real input, or the given example.
+    ///
+    /// A real input missing from the data directory is downloaded when `AOC_SESSION` is set. With
+    /// an input file or stdin, the example only tells that the input is a test one.
+    fn get_data(&self, source: &InputSource, example: Option<&str>) -> Result<String, AocError> {
+        let data: String = match (source, example) {
+            (InputSource::DataDir(data_dir), Some(name)) => {
+                read_input_file(&self.build_test_path(data_dir, name))?
+            }
+            (InputSource::DataDir(data_dir), None) => {
+                CachedProvider::from_env(data_dir).fetch(self.get_day_number())?
+            }
+            (InputSource::File(path), _) => read_input_file(path)?,
+            (InputSource::Stdin, _) => {
+                let mut data: String = String::new();
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(|source| AocError::MissingInput {
+                        path: String::from("-"),
+                        source,
+                    })?;
+                data
+            }
         };
-        fs::read_to_string(path).expect("File error")
+
+        // Inputs may have been saved on Windows, parsers only expect '\n'
+        Ok(data.replace("\r\n", "\n"))
     }
 
     // Solution parsing
 
-    fn solve_part_one(&mut self, _is_test: bool) -> i128 {
-        -1
+    /// Parse the input and store it, so that both parts can then be solved independently.
+    fn parse(&mut self, _input: &str) -> Result<(), AocError> {
+        Ok(())
+    }
+
+    // Solution solving
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(Answer::NotImplemented)
     }
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        -2
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(Answer::NotImplemented)
+    }
+
+    /// Solve the given part, `1` or `2`.
+    fn solve_part(&self, part: usize, params: &Params) -> Result<Answer, AocError> {
+        match part {
+            1 => self.solve_part_one(params),
+            _ => self.solve_part_two(params),
+        }
     }
 }
@@ -0,0 +1,145 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::aoc::{Aoc24Solution, InputSource, Params};
+use crate::cli::{OutputFormat, PartSelection};
+use crate::error::AocError;
+
+/// Timings of a step (parsing or solving a part) over several runs.
+#[derive(Clone, Debug, Serialize)]
+pub struct BenchRecord {
+    pub day: usize,
+    pub input: String, // Name of the example, or "real"
+    pub step: &'static str,
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+}
+
+impl BenchRecord {
+    fn new(day: usize, input: &str, step: &'static str, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let runs: usize = samples.len();
+        let p95_index: usize = (runs * 95).div_ceil(100).saturating_sub(1);
+
+        BenchRecord {
+            day,
+            input: input.to_string(),
+            step,
+            runs,
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[runs / 2].as_nanos(),
+            p95_ns: samples[p95_index].as_nanos(),
+        }
+    }
+}
+
+/// Run parsing and the selected parts `warmup + runs` times, only the last `runs` being measured.
+///
+/// Parts which are not implemented are left out of the records. The example is `None` for the real
+/// input.
+pub fn bench_day(
+    solution: &mut dyn Aoc24Solution,
+    source: &InputSource,
+    example: Option<&str>,
+    overrides: &[(String, i64)],
+    parts: PartSelection,
+    runs: usize,
+    warmup: usize,
+) -> Result<Vec<BenchRecord>, AocError> {
+    let day: usize = solution.get_day_number();
+    let params: Params = Params::resolve(&solution.get_params(), example.is_some(), overrides)?;
+    let input: String = solution.get_data(source, example)?;
+    let runs: usize = runs.max(1);
+    let parts: Vec<usize> = parts.parts(solution.get_part_count());
+
+    let mut parse_samples: Vec<Duration> = Vec::with_capacity(runs);
+    let mut part_samples: [Vec<Duration>; 2] = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+
+    for run in 0..(warmup + runs) {
+        let is_measured: bool = run >= warmup;
+
+        let (result, elapsed) = measure(|| solution.parse(&input));
+        result?;
+        if is_measured {
+            parse_samples.push(elapsed);
+        }
+
+        for &part in &parts {
+            let (answer, elapsed) = measure(|| solution.solve_part(part, &params));
+            if answer?.is_solved() && is_measured {
+                part_samples[part - 1].push(elapsed);
+            }
+        }
+    }
+
+    let name: &str = example.unwrap_or("real");
+    let mut records: Vec<BenchRecord> = vec![BenchRecord::new(day, name, "parse", parse_samples)];
+    for (step, samples) in ["part1", "part2"].into_iter().zip(part_samples) {
+        if !samples.is_empty() {
+            records.push(BenchRecord::new(day, name, step, samples));
+        }
+    }
+    Ok(records)
+}
+
+fn measure<T, F: FnOnce() -> Result<T, AocError>>(step: F) -> (Result<T, AocError>, Duration) {
+    let now: Instant = Instant::now();
+    let result: Result<T, AocError> = step();
+    (result, now.elapsed())
+}
+
+pub fn print_records(records: &[BenchRecord], format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_text(records),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(records).expect("Records are serializable")
+        ),
+        OutputFormat::Csv => print_csv(records),
+    }
+}
+
+const INPUT_WIDTH: usize = 10;
+const TIME_WIDTH: usize = 12;
+
+fn print_text(records: &[BenchRecord]) {
+    let header: String = format!(
+        "{:<5} | {:<INPUT_WIDTH$} | {:<5} | {:>5} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$}",
+        "Day", "Input", "Step", "Runs", "Min", "Median", "P95"
+    );
+    println!("{header}");
+    println!("{}", "-".repeat(header.len()));
+
+    let as_micros = |nanos: u128| format!("{} us", nanos / 1000);
+    for record in records {
+        println!(
+            "{:<5} | {:<INPUT_WIDTH$} | {:<5} | {:>5} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$}",
+            format!("{:0>2}", record.day),
+            record.input,
+            record.step,
+            record.runs,
+            as_micros(record.min_ns),
+            as_micros(record.median_ns),
+            as_micros(record.p95_ns),
+        );
+    }
+}
+
+fn print_csv(records: &[BenchRecord]) {
+    println!("day,input,step,runs,min_ns,median_ns,p95_ns");
+    for record in records {
+        println!(
+            "{},{},{},{},{},{},{}",
+            record.day,
+            record.input,
+            record.step,
+            record.runs,
+            record.min_ns,
+            record.median_ns,
+            record.p95_ns
+        );
+    }
+}
@@ -1,16 +1,555 @@
-use clap::Parser;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{ArgAction, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+
+use crate::aoc::{resolve_data_dir, Example, InputSource};
+use crate::registry::available_days;
+
+const DAY_HELP: &str =
+    "Number of the day to run, a range of days (e.g. '5..12', '5..=12') or 'all'";
 
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
-    // Number of the day to run
-    #[arg(help = "Number of the day to run")]
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    // Day(s) to run, always given when there is no subcommand
+    #[arg(required = true, help = DAY_HELP)]
+    pub day: Option<DaySelection>,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[arg(long, value_enum, default_value_t = PartSelection::Both, help = "Part(s) to solve")]
+    pub part: PartSelection,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with = "input",
+        help = "Compare answers against the ones stored in <DATA_DIR>/answers.toml"
+    )]
+    pub check: bool,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with = "input",
+        help = "Store computed answers into <DATA_DIR>/answers.toml"
+    )]
+    pub record: bool,
+
+    #[arg(
+        short('j'),
+        long,
+        default_value_t = 1,
+        help = "Number of days solved at once, 0 for one per core"
+    )]
+    pub jobs: usize,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        value_parser = parse_timeout,
+        help = "Give up on parsing or a part after this many seconds, e.g. '30' or '0.5'"
+    )]
+    pub timeout: Option<Duration>,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help = "Output format")]
+    pub format: OutputFormat,
+
+    #[command(flatten)]
+    pub frames: FrameArgs,
+
+    #[command(flatten)]
+    pub log: LogArgs,
+}
+
+/// Recording of the simulations' steps, off unless a directory is given.
+#[derive(Debug, clap::Args)]
+pub struct FrameArgs {
+    #[arg(
+        long = "record-frames",
+        value_name = "DIR",
+        help = "Record each step of the simulating days into this directory"
+    )]
+    pub dir: Option<String>,
+
+    #[arg(
+        long = "frame-format",
+        value_enum,
+        value_delimiter = ',',
+        default_value = "gif",
+        requires = "dir",
+        help = "Export recorded frames as an animated GIF, PNG files and/or text for 'play'"
+    )]
+    pub formats: Vec<FrameFormat>,
+
+    #[arg(
+        long = "frame-scale",
+        value_name = "PIXELS",
+        default_value_t = 4,
+        requires = "dir",
+        help = "Size of a cell in exported images"
+    )]
+    pub scale: usize,
+
+    #[arg(
+        long = "frame-delay",
+        value_name = "MS",
+        default_value_t = 100,
+        requires = "dir",
+        help = "Time between two frames of a GIF"
+    )]
+    pub delay: u64,
+
+    #[arg(
+        long = "frame-limit",
+        default_value_t = 1000,
+        requires = "dir",
+        help = "Frames kept per part, the oldest ones being dropped beyond it"
+    )]
+    pub limit: usize,
+}
+
+/// Diagnostics of the solutions, shared by every command.
+#[derive(Debug, clap::Args)]
+pub struct LogArgs {
+    #[arg(
+        short('v'),
+        long,
+        global = true,
+        action = ArgAction::Count,
+        help = "Show diagnostics on stderr, more with each use (info, debug, then trace)"
+    )]
+    pub verbose: u8,
+
+    #[arg(
+        short('q'),
+        long,
+        global = true,
+        default_value_t = false,
+        conflicts_with = "verbose",
+        help = "Only show errors on stderr"
+    )]
+    pub quiet: bool,
+
+    #[arg(
+        long = "log",
+        value_name = "DAY=LEVEL",
+        global = true,
+        value_parser = parse_log_level,
+        help = "Set the diagnostics level of a day, e.g. '22=debug' (repeatable)"
+    )]
+    pub days: Vec<(usize, LevelFilter)>,
+}
+
+impl LogArgs {
+    /// Level of the days without their own, warnings being shown by default.
+    pub fn level(&self) -> LevelFilter {
+        match (self.quiet, self.verbose) {
+            (true, _) => LevelFilter::Error,
+            (false, 0) => LevelFilter::Warn,
+            (false, 1) => LevelFilter::Info,
+            (false, 2) => LevelFilter::Debug,
+            (false, _) => LevelFilter::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Measure parsing and both parts over several runs
+    Bench(BenchArgs),
+    /// Generate the module of a new day, along with its empty data files
+    New(NewArgs),
+    /// Solve a day again whenever its inputs or sources change
+    Watch(WatchArgs),
+    /// Compare the answers of the Rust and Python solutions on the same inputs
+    Parity(ParityArgs),
+    /// Play frames recorded with '--record-frames --frame-format text' in the terminal
+    Play(PlayArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct PlayArgs {
+    #[arg(help = "Text file of recorded frames, e.g. 'frames/day14-part2.txt'")]
+    pub file: String,
+
+    #[arg(long, default_value_t = 10.0, help = "Frames shown per second")]
+    pub fps: f64,
+
+    #[arg(
+        long,
+        default_value_t = 1,
+        help = "Show one frame out of this many, to speed up long recordings"
+    )]
+    pub every: usize,
+
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with = "fps",
+        help = "Wait for Enter between two frames"
+    )]
+    pub step: bool,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ParityArgs {
+    #[arg(help = DAY_HELP)]
+    pub day: DaySelection,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[arg(
+        long,
+        value_name = "PROGRAM",
+        help = "Python interpreter [default: $AOC_PYTHON or python3]"
+    )]
+    pub python: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Directory holding the aoc24 Python package [default: ../Python3 from this crate]"
+    )]
+    pub python_dir: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct WatchArgs {
+    #[arg(help = "Number of the day to watch")]
     pub day: usize,
 
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[arg(long, value_enum, default_value_t = PartSelection::Both, help = "Part(s) to solve")]
+    pub part: PartSelection,
+
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        value_parser = parse_timeout,
+        help = "Give up on parsing or a part after this many seconds, e.g. '30' or '0.5'"
+    )]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct NewArgs {
+    #[arg(help = "Number of the day to create")]
+    pub day: usize,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Directory holding inputs/ and tests/ [default: $AOC_DATA_DIR or data]"
+    )]
+    pub data_dir: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct BenchArgs {
+    #[arg(help = DAY_HELP)]
+    pub day: DaySelection,
+
+    #[command(flatten)]
+    pub inputs: InputArgs,
+
+    #[arg(long, value_enum, default_value_t = PartSelection::Both, help = "Part(s) to solve")]
+    pub part: PartSelection,
+
+    #[arg(
+        short('n'),
+        long,
+        default_value_t = 10,
+        help = "Number of measured runs"
+    )]
+    pub runs: usize,
+
+    #[arg(long, default_value_t = 1, help = "Number of runs before measuring")]
+    pub warmup: usize,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, help = "Output format")]
+    pub format: OutputFormat,
+}
+
+/// Location of the inputs, shared by runs and benchmarks.
+#[derive(Debug, clap::Args)]
+pub struct InputArgs {
     #[arg(
         short('t'),
         long,
         default_value_t = false,
-        help = "Whether to use regular or test input"
+        help = "Whether to use regular or test input (the day's first example)"
     )]
     pub use_test: bool,
+
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Use the named example instead of the real input, or every example with 'all'"
+    )]
+    pub test: Option<ExampleSelection>,
+
+    #[arg(
+        long,
+        value_name = "FILE",
+        help = "Read the input from a file instead, or from stdin with '-' (single day only)"
+    )]
+    pub input: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Directory holding inputs/, tests/ and answers.toml [default: $AOC_DATA_DIR or data]"
+    )]
+    pub data_dir: Option<String>,
+
+    #[arg(
+        long = "param",
+        value_name = "NAME=VALUE",
+        value_parser = parse_param,
+        help = "Override a puzzle parameter, e.g. 'width=11' for day 14 (repeatable)"
+    )]
+    pub params: Vec<(String, i64)>,
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or(format!(
+            "Invalid timeout '{value}', expected a positive number of seconds"
+        ))
+}
+
+fn parse_log_level(value: &str) -> Result<(usize, LevelFilter), String> {
+    let (day, level) = value
+        .split_once('=')
+        .ok_or(format!("Expected DAY=LEVEL, got '{value}'"))?;
+    let day: usize = day
+        .trim()
+        .trim_start_matches("day")
+        .parse()
+        .map_err(|_| format!("Invalid day '{day}'"))?;
+    let level: LevelFilter = level.trim().parse().map_err(|_| {
+        format!("Invalid level '{level}', expected off, error, warn, info, debug or trace")
+    })?;
+    Ok((day, level))
+}
+
+fn parse_param(value: &str) -> Result<(String, i64), String> {
+    let (name, number) = value
+        .split_once('=')
+        .ok_or(format!("Expected NAME=VALUE, got '{value}'"))?;
+    let number: i64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid value '{number}' for parameter '{name}'"))?;
+    Ok((name.trim().to_string(), number))
+}
+
+impl InputArgs {
+    pub fn source(&self) -> InputSource {
+        InputSource::resolve(self.input.as_deref(), self.data_dir.as_deref())
+    }
+
+    pub fn data_dir(&self) -> PathBuf {
+        resolve_data_dir(self.data_dir.as_deref())
+    }
+
+    /// Examples to use, `None` meaning the real input.
+    pub fn examples(&self) -> Option<ExampleSelection> {
+        match (&self.test, self.use_test) {
+            (Some(selection), _) => Some(selection.clone()),
+            (None, true) => Some(ExampleSelection::First),
+            (None, false) => None,
+        }
+    }
+}
+
+/// Example(s) targeted by a run, instead of the real input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExampleSelection {
+    First,
+    Named(String),
+    All,
+}
+
+impl ExampleSelection {
+    /// Names of the selected examples among the given ones, which may be none.
+    pub fn select(&self, examples: &[Example]) -> Vec<&'static str> {
+        let names = examples.iter().map(|example| example.name);
+        match self {
+            ExampleSelection::First => names.take(1).collect(),
+            ExampleSelection::Named(target) => names.filter(|name| name == target).collect(),
+            ExampleSelection::All => names.collect(),
+        }
+    }
+}
+
+impl FromStr for ExampleSelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "" => Err(String::from("Empty example name")),
+            value if value.eq_ignore_ascii_case("all") => Ok(ExampleSelection::All),
+            name => Ok(ExampleSelection::Named(name.to_string())),
+        }
+    }
+}
+
+/// Part(s) of a day to solve, parsing always being done.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl PartSelection {
+    /// Numbers of the selected parts, among those of a day having `part_count` parts.
+    pub fn parts(self, part_count: usize) -> Vec<usize> {
+        let parts: Vec<usize> = match self {
+            PartSelection::One => vec![1],
+            PartSelection::Two => vec![2],
+            PartSelection::Both => vec![1, 2],
+        };
+        parts
+            .into_iter()
+            .filter(|part| *part <= part_count)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum FrameFormat {
+    Gif,
+    Png,
+    Text,
+}
+
+/// Parse the command line, the help listing the days having a solution.
+pub fn parse_args() -> Args {
+    let days: String = format_days(&available_days());
+    let matches = Args::command()
+        .after_help(format!("Available days: {days}"))
+        .get_matches();
+
+    Args::from_arg_matches(&matches).unwrap_or_else(|error| error.exit())
+}
+
+/// Format sorted days as ranges, e.g. `0..=20, 22..=25`.
+fn format_days(days: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == day => *last = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+
+    ranges
+        .iter()
+        .map(|(first, last)| match first == last {
+            true => format!("{first}"),
+            false => format!("{first}..={last}"),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Day of the template, see `day00`.
+const TEMPLATE_DAY: usize = 0;
+
+/// Day(s) targeted by a run.
+///
+/// Ranges follow Rust's syntax: `5..12` excludes day 12 while `5..=12` includes it.
+///
+/// Day 0 is the template of new days, which only runs when selected on its own.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DaySelection {
+    Single(usize),
+    Range(usize, usize), // Inclusive bounds
+    All,
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: usize) -> bool {
+        match self {
+            DaySelection::Single(target) => day == *target,
+            _ if day == TEMPLATE_DAY => false,
+            DaySelection::Range(first, last) => (*first..=*last).contains(&day),
+            DaySelection::All => true,
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value: &str = value.trim();
+
+        if value.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+
+        let parse_day = |day: &str| -> Result<usize, String> {
+            day.parse::<usize>()
+                .map_err(|_| format!("Invalid day number '{day}'"))
+        };
+
+        let (first, last): (usize, usize) = if let Some((start, end)) = value.split_once("..=") {
+            let first: usize = if start.is_empty() {
+                1
+            } else {
+                parse_day(start)?
+            };
+            (first, parse_day(end)?)
+        } else if let Some((start, end)) = value.split_once("..") {
+            let first: usize = if start.is_empty() {
+                1
+            } else {
+                parse_day(start)?
+            };
+            let last: usize = match end {
+                "" => 25,
+                _ => parse_day(end)?
+                    .checked_sub(1)
+                    .ok_or(format!("Empty day range '{value}'"))?,
+            };
+            (first, last)
+        } else {
+            return Ok(DaySelection::Single(parse_day(value)?));
+        };
+
+        if first > last {
+            return Err(format!("Empty day range '{value}'"));
+        }
+
+        Ok(DaySelection::Range(first, last))
+    }
 }
@@ -1,13 +1,17 @@
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Aoc24Solution, Example};
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day00 {}
 
+register_solution!(Day00);
+
 impl Aoc24Solution for Day00 {
     fn get_day_number(&self) -> usize {
         0
     }
-    fn get_extra_name(&self) -> Option<&str> {
-        Some("test")
+
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new("test", None, None)]
     }
 }
@@ -1,9 +1,11 @@
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day01::parse::parse_input;
 use crate::day01::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day01 {
@@ -11,17 +13,27 @@ pub struct Day01 {
     right_list: Vec<i32>,
 }
 
+register_solution!(Day01);
+
 impl Aoc24Solution for Day01 {
     fn get_day_number(&self) -> usize {
         1
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.left_list, self.right_list) = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.left_list, &self.right_list)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("11"), Some("31"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.left_list, self.right_list) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.left_list, &self.right_list).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.left_list, &self.right_list)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.left_list, &self.right_list).into())
     }
 }
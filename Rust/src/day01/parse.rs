@@ -4,17 +4,20 @@ use nom::sequence::tuple;
 use nom::{IResult, Parser};
 use std::num::ParseIntError;
 
-pub fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
+
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), AocError> {
     let mut list1: Vec<i32> = Vec::with_capacity(1000);
     let mut list2: Vec<i32> = Vec::with_capacity(1000);
 
-    for line in input.lines() {
-        let (_, (a, b)): (_, (i32, i32)) = parse_line(line).unwrap();
+    for (line_index, line) in input.lines().enumerate() {
+        let (a, b): (i32, i32) = parse_line_with(line_index, line, parse_line)?;
         list1.push(a);
         list2.push(b)
     }
 
-    (list1, list2)
+    Ok((list1, list2))
 }
 
 fn parse_line(line: &str) -> IResult<&str, (i32, i32)> {
@@ -1,26 +1,38 @@
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day02::parse::parse_input;
 use crate::day02::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day02 {
     reports: Vec<Vec<i32>>,
 }
 
+register_solution!(Day02);
+
 impl Aoc24Solution for Day02 {
     fn get_day_number(&self) -> usize {
         2
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.reports = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.reports)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("2"), Some("4"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.reports = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.reports).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.reports)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.reports).into())
     }
 }
@@ -5,15 +5,18 @@ use nom::sequence::tuple;
 use nom::{IResult, Parser};
 use std::num::ParseIntError;
 
-pub fn parse_input(input: &str) -> Vec<Vec<i32>> {
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
     let mut reports: Vec<Vec<i32>> = Vec::with_capacity(1000);
 
-    for line in input.lines() {
-        let (_, report): (_, Vec<i32>) = parse_line(line).unwrap();
+    for (line_index, line) in input.lines().enumerate() {
+        let report: Vec<i32> = parse_line_with(line_index, line, parse_line)?;
         reports.push(report)
     }
 
-    reports
+    Ok(reports)
 }
 
 fn parse_line(line: &str) -> IResult<&str, Vec<i32>> {
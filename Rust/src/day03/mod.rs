@@ -2,27 +2,39 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day03::model::Instruction;
 use crate::day03::parse::parse_input;
 use crate::day03::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day03 {
     instructions: Vec<Instruction>,
 }
 
+register_solution!(Day03);
+
 impl Aoc24Solution for Day03 {
     fn get_day_number(&self) -> usize {
         3
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.instructions = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.instructions)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("161"), Some("48"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.instructions = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.instructions).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.instructions)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.instructions).into())
     }
 }
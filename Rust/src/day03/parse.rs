@@ -1,27 +1,36 @@
 use crate::day03::model::Instruction;
+use crate::error::AocError;
+use crate::toolbox::parsing::offset_to_position;
 use regex::{Captures, Regex};
 
-pub fn parse_input(input: &str) -> Vec<Instruction> {
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, AocError> {
     let instruction_regex: Regex = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
-    let mul_instruction_regex: Regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
 
     let mut result: Vec<Instruction> = Vec::new();
 
     // Iter on all matches
-    for mtc in instruction_regex.find_iter(input) {
+    for captures in instruction_regex.captures_iter(input) {
+        let mtc = captures.get(0).unwrap(); // Group 0 is always the whole match
         match mtc.as_str() {
             "don't()" => result.push(Instruction::Dont),
             "do()" => result.push(Instruction::Do),
-            _ => result.push(parse_mul_instruction(&mul_instruction_regex, mtc.as_str())),
+            _ => result.push(parse_mul_instruction(&captures).map_err(|message| {
+                let (line, column) = offset_to_position(input, mtc.start());
+                AocError::parse(line, column, message)
+            })?),
         }
     }
 
-    result
+    Ok(result)
 }
 
-fn parse_mul_instruction(mul_regex: &Regex, value: &str) -> Instruction {
-    let captures: Captures = mul_regex.captures(value).unwrap();
-    let left_val = captures.get(1).unwrap().as_str().parse::<i32>().unwrap();
-    let right_val = captures.get(2).unwrap().as_str().parse::<i32>().unwrap();
-    Instruction::Mul(left_val, right_val)
+fn parse_mul_instruction(captures: &Captures) -> Result<Instruction, String> {
+    let parse_operand = |index: usize| -> Result<i32, String> {
+        let value: &str = captures.get(index).map_or("", |operand| operand.as_str());
+        value
+            .parse::<i32>()
+            .map_err(|error| format!("Invalid mul operand '{value}' ({error})"))
+    };
+
+    Ok(Instruction::Mul(parse_operand(1)?, parse_operand(2)?))
 }
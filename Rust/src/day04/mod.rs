@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day04::model::Letter;
 use crate::day04::parse::parse_input;
 use crate::day04::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::Grid;
 
 #[derive(Default)]
@@ -13,17 +15,27 @@ pub struct Day04 {
     grid: Grid<Letter>,
 }
 
+register_solution!(Day04);
+
 impl Aoc24Solution for Day04 {
     fn get_day_number(&self) -> usize {
         4
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.grid = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.grid)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("18"), Some("9"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.grid = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.grid).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.grid)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.grid).into())
     }
 }
@@ -1,7 +1,7 @@
 use crate::toolbox::{Coordinates, Direction, Grid};
 use std::cmp::PartialEq;
 
-#[derive(Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum Letter {
     X,
     M,
@@ -12,28 +12,24 @@ pub enum Letter {
 }
 
 impl Grid<Letter> {
-    /// Count the number of provided word found, starting at this position
-    pub fn count_xmas(&self, coordinates: &Coordinates, expected: &[Letter]) -> i32 {
+    /// Count the words written eastward or south-eastward, starting anywhere.
+    pub fn count_words(&self, expected: &[Letter]) -> i32 {
         let mut counter: i32 = 0;
 
-        // Must start with 'X'
-        if self.get_ref(coordinates) != Some(expected.first().expect("Empty expected")) {
-            return counter;
-        }
-
-        'dir_loop: for direction in Direction::neighbours8() {
-            let mut current_position: Coordinates = *coordinates;
-
-            for current_expected in &expected[1..] {
-                // Loop increment
-                current_position = current_position.step(&direction);
-
-                if self.get_ref(&current_position) != Some(current_expected) {
-                    continue 'dir_loop;
+        for start in self.coordinates() {
+            for direction in [Direction::E, Direction::SE] {
+                let mut current_position: Coordinates = start;
+                let is_found: bool = expected.iter().all(|current_expected| {
+                    let is_expected: bool =
+                        self.get_ref(&current_position) == Some(current_expected);
+                    current_position = current_position.step(&direction);
+                    is_expected
+                });
+
+                if is_found {
+                    counter += 1
                 }
             }
-
-            counter += 1
         }
 
         counter
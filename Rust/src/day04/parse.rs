@@ -1,22 +1,20 @@
 use crate::day04::model::Letter;
-use crate::toolbox::Grid;
+use crate::error::AocError;
+use crate::toolbox::{FromGridChar, Grid};
 
-pub fn parse_input(input: &str) -> Grid<Letter> {
-    let mut data: Vec<Vec<Letter>> = Vec::with_capacity(140);
-
-    for line in input.lines() {
-        let mut sub_data: Vec<Letter> = Vec::new();
-        for chr in line.chars() {
-            match chr {
-                'X' => sub_data.push(Letter::X),
-                'M' => sub_data.push(Letter::M),
-                'A' => sub_data.push(Letter::A),
-                'S' => sub_data.push(Letter::S),
-                _ => sub_data.push(Letter::Irrelevant),
-            }
-        }
-        data.push(sub_data)
+impl FromGridChar for Letter {
+    fn from_grid_char(chr: char) -> Option<Self> {
+        Some(match chr {
+            'X' => Letter::X,
+            'M' => Letter::M,
+            'A' => Letter::A,
+            'S' => Letter::S,
+            _ => Letter::Irrelevant,
+        })
     }
+}
 
-    Grid::new(data)
+pub fn parse_input(input: &str) -> Result<Grid<Letter>, AocError> {
+    let (grid, _) = Grid::parse(input, &[])?;
+    Ok(grid)
 }
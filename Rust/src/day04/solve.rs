@@ -2,22 +2,12 @@ use crate::day04::model::Letter;
 use crate::toolbox::{Coordinates, Grid};
 
 pub fn solve_part_one(grid: &Grid<Letter>) -> i128 {
-    let mut counter: i32 = 0;
     let word: [Letter; 4] = [Letter::X, Letter::M, Letter::A, Letter::S];
 
-    for i in 0..grid.depth() {
-        for j in 0..grid.width() {
-            counter += grid.count_xmas(
-                &Coordinates {
-                    x: i as i32,
-                    y: j as i32,
-                },
-                &word,
-            )
-        }
-    }
-
-    counter as i128
+    // Going east and south-east in each rotation covers the eight directions
+    (0..4)
+        .map(|quarter_turns| grid.rotate_clockwise(quarter_turns).count_words(&word))
+        .sum::<i32>() as i128
 }
 
 pub fn solve_part_two(grid: &Grid<Letter>) -> i128 {
@@ -1,9 +1,11 @@
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day05::parse::parse_input;
 use crate::day05::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day05 {
@@ -11,17 +13,27 @@ pub struct Day05 {
     updates: Vec<Vec<i32>>,
 }
 
+register_solution!(Day05);
+
 impl Aoc24Solution for Day05 {
     fn get_day_number(&self) -> usize {
         5
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.rules, self.updates) = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.rules, &self.updates)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("143"), Some("123"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.rules, self.updates) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.rules, &self.updates).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.rules, &mut self.updates)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.rules, &self.updates).into())
     }
 }
@@ -6,13 +6,19 @@ use nom::sequence::{separated_pair, tuple};
 use nom::{IResult, Parser};
 use std::num::ParseIntError;
 
-pub fn parse_input(input: &str) -> (Vec<(i32, i32)>, Vec<Vec<i32>>) {
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
+
+type Rule = (i32, i32);
+type Update = Vec<i32>;
+
+pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), AocError> {
     let mut rules: Vec<(i32, i32)> = Vec::with_capacity(1200);
     let mut updates: Vec<Vec<i32>> = Vec::with_capacity(300);
 
     let mut found_empty_line: bool = false;
 
-    for line in input.lines() {
+    for (line_index, line) in input.lines().enumerate() {
         if line.is_empty() {
             found_empty_line = true;
             continue;
@@ -20,15 +26,15 @@ pub fn parse_input(input: &str) -> (Vec<(i32, i32)>, Vec<Vec<i32>>) {
 
         // Regular parsing
         if !found_empty_line {
-            let (_, (left, right)) = parse_rule(line).unwrap();
+            let (left, right) = parse_line_with(line_index, line, parse_rule)?;
             rules.push((left, right));
         } else {
-            let (_, update) = parse_update(line).unwrap();
+            let update = parse_line_with(line_index, line, parse_update)?;
             updates.push(update);
         }
     }
 
-    (rules, updates)
+    Ok((rules, updates))
 }
 
 fn parse_rule(rule_line: &str) -> IResult<&str, (i32, i32)> {
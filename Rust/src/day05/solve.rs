@@ -38,7 +38,7 @@ fn is_update_valid(update: &[i32], rules: &[(i32, i32)]) -> (bool, Option<(usize
     (true, None)
 }
 
-pub fn solve_part_two(rules: &[(i32, i32)], updates: &mut [Vec<i32>]) -> i128 {
+pub fn solve_part_two(rules: &[(i32, i32)], updates: &[Vec<i32>]) -> i128 {
     let mut result: i32 = 0;
 
     for update in updates {
@@ -49,9 +49,12 @@ pub fn solve_part_two(rules: &[(i32, i32)], updates: &mut [Vec<i32>]) -> i128 {
             continue;
         }
 
+        // Reorder a copy, the parsed updates are shared with part one
+        let mut update: Vec<i32> = update.clone();
+
         loop {
             let (is_valid, indexes): (bool, Option<(usize, usize)>) =
-                is_update_valid(update, rules);
+                is_update_valid(&update, rules);
 
             if is_valid {
                 break;
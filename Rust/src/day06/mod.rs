@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day06::model::Tile06;
 use crate::day06::parse::parse_input;
 use crate::day06::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Grid};
 
 #[derive(Default)]
@@ -14,17 +16,27 @@ pub struct Day06 {
     starting_position: Coordinates,
 }
 
+register_solution!(Day06);
+
 impl Aoc24Solution for Day06 {
     fn get_day_number(&self) -> usize {
         6
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.grid, self.starting_position) = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.grid, &self.starting_position)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("41"), Some("6"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.grid, self.starting_position) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.grid, &self.starting_position).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.grid, &self.starting_position)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.grid, &self.starting_position).into())
     }
 }
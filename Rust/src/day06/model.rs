@@ -1,18 +1,25 @@
+use crate::toolbox::render::{RenderTile, Rgb};
 use crate::toolbox::Grid;
-use std::fmt::{Debug, Formatter};
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
 pub enum Tile06 {
     #[default]
     Free,
     Blocked,
 }
 
-impl Debug for Tile06 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile06 {
+    fn glyph(&self) -> char {
         match self {
-            Tile06::Free => write!(f, "."),
-            Tile06::Blocked => write!(f, "#"),
+            Tile06::Free => '.',
+            Tile06::Blocked => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile06::Free => Rgb::WHITE,
+            Tile06::Blocked => Rgb::DARK_GREY,
         }
     }
 }
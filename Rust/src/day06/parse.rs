@@ -1,31 +1,20 @@
 use crate::day06::model::Tile06;
-use crate::toolbox::{Coordinates, Grid};
+use crate::error::AocError;
+use crate::toolbox::{Coordinates, FromGridChar, Grid, Markers};
 
-pub fn parse_input(input: &str) -> (Grid<Tile06>, Coordinates) {
-    let mut data: Vec<Vec<Tile06>> = Vec::with_capacity(140);
-    let mut starting_position: Option<Coordinates> = None;
+const START: char = '^';
 
-    for (line_index, line) in input.lines().enumerate() {
-        let mut sub_data: Vec<Tile06> = Vec::new();
-        for (chr_index, chr) in line.chars().enumerate() {
-            match chr {
-                '#' => sub_data.push(Tile06::Blocked),
-                '.' => sub_data.push(Tile06::Free),
-                '^' => {
-                    starting_position = Some(Coordinates {
-                        x: line_index as i32,
-                        y: chr_index as i32,
-                    });
-                    sub_data.push(Tile06::Free)
-                }
-                _ => unreachable!("Unexpected input char {}", chr),
-            }
+impl FromGridChar for Tile06 {
+    fn from_grid_char(chr: char) -> Option<Self> {
+        match chr {
+            '#' => Some(Tile06::Blocked),
+            '.' | START => Some(Tile06::Free),
+            _ => None,
         }
-        data.push(sub_data)
     }
+}
 
-    (
-        Grid::new(data),
-        starting_position.expect("No starting position found"),
-    )
+pub fn parse_input(input: &str) -> Result<(Grid<Tile06>, Coordinates), AocError> {
+    let (grid, markers): (Grid<Tile06>, Markers) = Grid::parse(input, &[START])?;
+    Ok((grid, markers.single(START)?))
 }
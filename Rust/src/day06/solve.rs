@@ -4,7 +4,8 @@ use itertools::Itertools;
 use rayon::prelude::*;
 
 use crate::day06::model::Tile06;
-use crate::toolbox::{Coordinates, Direction, Grid};
+use crate::record_frame;
+use crate::toolbox::{Coordinates, Direction, Grid, Scene};
 
 pub fn solve_part_one(grid: &Grid<Tile06>, starting_position: &Coordinates) -> i128 {
     let mut walked_tiles: Vec<Coordinates> = Vec::new();
@@ -26,6 +27,10 @@ pub fn solve_part_one(grid: &Grid<Tile06>, starting_position: &Coordinates) -> i
                 current_position = next_position;
             }
         }
+
+        record_frame!(Scene::new(grid)
+            .path(&walked_tiles, 'X')
+            .mark(current_position, current_direction.arrow()));
     }
 
     // Removing tiles visited more than once
@@ -2,27 +2,39 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day07::model::{Equation, Operator};
 use crate::day07::parse::parse_input;
 use crate::day07::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day07 {
     equations: Vec<Equation>,
 }
 
+register_solution!(Day07);
+
 impl Aoc24Solution for Day07 {
     fn get_day_number(&self) -> usize {
         7
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.equations = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.equations)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("3749"), Some("11387"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.equations = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.equations).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.equations)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.equations).into())
     }
 }
@@ -7,10 +7,14 @@ use nom::sequence::tuple;
 use nom::{IResult, Parser};
 use std::num::ParseIntError;
 
-pub fn parse_input(input: &str) -> Vec<Equation> {
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
+
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, AocError> {
     input
         .lines()
-        .map(|line| parse_line(line).unwrap().1)
+        .enumerate()
+        .map(|(line_index, line)| parse_line_with(line_index, line, parse_line))
         .collect()
 }
 
@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day08::model::Tile08;
 use crate::day08::parse::parse_input;
 use crate::day08::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::Grid;
 
 #[derive(Default)]
@@ -13,17 +15,27 @@ pub struct Day08 {
     antenna_map: Grid<Tile08>,
 }
 
+register_solution!(Day08);
+
 impl Aoc24Solution for Day08 {
     fn get_day_number(&self) -> usize {
         8
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.antenna_map = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.antenna_map)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("14"), Some("34"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.antenna_map = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.antenna_map).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.antenna_map)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.antenna_map).into())
     }
 }
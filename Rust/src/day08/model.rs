@@ -1,19 +1,26 @@
+use crate::toolbox::render::{RenderTile, Rgb};
 use crate::toolbox::{CollectionHashMap, Coordinates, Grid};
 use std::collections::HashMap;
-use std::fmt::{Debug, Formatter};
 
-#[derive(Default, Eq, Hash, PartialEq)]
+#[derive(Debug, Default, Eq, Hash, PartialEq)]
 pub enum Tile08 {
     Antenna(char),
     #[default]
     Empty,
 }
 
-impl Debug for Tile08 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile08 {
+    fn glyph(&self) -> char {
         match self {
-            Tile08::Empty => write!(f, "."),
-            Tile08::Antenna(frequency) => write!(f, "{}", frequency),
+            Tile08::Empty => '.',
+            Tile08::Antenna(frequency) => *frequency,
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile08::Empty => Rgb::WHITE,
+            Tile08::Antenna(_) => Rgb::BLUE,
         }
     }
 }
@@ -1,19 +1,17 @@
 use crate::day08::model::Tile08;
-use crate::toolbox::Grid;
+use crate::error::AocError;
+use crate::toolbox::{FromGridChar, Grid};
 
-pub fn parse_input(input: &str) -> Grid<Tile08> {
-    let mut data: Vec<Vec<Tile08>> = Vec::with_capacity(140);
-
-    for line in input.lines() {
-        let mut sub_data: Vec<Tile08> = Vec::new();
-        for chr in line.chars() {
-            match chr {
-                '.' => sub_data.push(Tile08::Empty),
-                _ => sub_data.push(Tile08::Antenna(chr)),
-            }
-        }
-        data.push(sub_data)
+impl FromGridChar for Tile08 {
+    fn from_grid_char(chr: char) -> Option<Self> {
+        Some(match chr {
+            '.' => Tile08::Empty,
+            _ => Tile08::Antenna(chr),
+        })
     }
+}
 
-    Grid::new(data)
+pub fn parse_input(input: &str) -> Result<Grid<Tile08>, AocError> {
+    let (grid, _) = Grid::parse(input, &[])?;
+    Ok(grid)
 }
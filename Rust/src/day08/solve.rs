@@ -9,8 +9,8 @@ pub fn solve_part_one(map: &Grid<Tile08>) -> i128 {
 
     let result: usize = antennas
         .hash_map
-        .iter()
-        .flat_map(|(_key, value)| {
+        .values()
+        .flat_map(|value| {
             let mut result: Vec<Coordinates> = Vec::with_capacity(2);
 
             for combination in value.iter().combinations(2) {
@@ -42,8 +42,8 @@ pub fn solve_part_two(map: &Grid<Tile08>) -> i128 {
 
     let result: usize = antennas
         .hash_map
-        .iter()
-        .flat_map(|(_key, value)| {
+        .values()
+        .flat_map(|value| {
             let mut result: Vec<Coordinates> = Vec::with_capacity(2);
 
             for combination in value.iter().combinations(2) {
@@ -1,26 +1,38 @@
 mod model;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day09::model::Memory;
 use crate::day09::solve::solve_part_two;
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day09 {
     memory: Memory,
 }
 
+register_solution!(Day09);
+
 impl Aoc24Solution for Day09 {
     fn get_day_number(&self) -> usize {
         9
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.memory = Memory::from_string(&self.get_data(is_test));
-        self.memory.optimize_space().checksum() as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("1928"), Some("2858"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.memory = Memory::from_string(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(self.memory.optimize_space().checksum().into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.memory)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.memory).into())
     }
 }
@@ -1,6 +1,9 @@
 use nom::character::complete::i128;
 use std::fmt::{write, Debug, Formatter};
 
+use crate::error::AocError;
+use crate::toolbox::parsing::unexpected_char;
+
 const CAPACITY: usize = 20_000; // From input's length
 
 #[derive(Default)]
@@ -68,14 +71,16 @@ impl Memory {
         }
     }
 
-    pub fn from_string(value: &str) -> Self {
+    pub fn from_string(value: &str) -> Result<Self, AocError> {
         let mut blocks: Vec<MemoryBlock> = Vec::with_capacity(CAPACITY);
         let mut objects: Vec<MemoryObject> = Vec::with_capacity(CAPACITY);
 
         let mut next_file_id: usize = 0;
 
         for (index, len) in value.trim().chars().enumerate() {
-            let length: usize = len.to_string().parse::<usize>().unwrap();
+            let length: usize =
+                len.to_digit(10)
+                    .ok_or_else(|| unexpected_char(0, index, len))? as usize;
             let block: MemoryBlock = {
                 match index % 2 {
                     0 => {
@@ -100,7 +105,7 @@ impl Memory {
             }
         }
 
-        Self { blocks, objects }
+        Ok(Self { blocks, objects })
     }
 
     pub fn checksum(&self) -> usize {
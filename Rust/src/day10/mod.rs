@@ -1,9 +1,11 @@
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day10::parse::parse_input;
 use crate::day10::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::Grid;
 
 #[derive(Default)]
@@ -11,17 +13,27 @@ pub struct Day10 {
     topographic_map: Grid<u32>,
 }
 
+register_solution!(Day10);
+
 impl Aoc24Solution for Day10 {
     fn get_day_number(&self) -> usize {
         10
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.topographic_map = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.topographic_map)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("36"), Some("81"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.topographic_map = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.topographic_map).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.topographic_map)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.topographic_map).into())
     }
 }
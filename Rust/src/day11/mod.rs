@@ -2,27 +2,42 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day11::model::Stone;
 use crate::day11::parse::parse_input;
 use crate::day11::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day11 {
     stones: Vec<Stone>,
 }
 
+register_solution!(Day11);
+
 impl Aoc24Solution for Day11 {
     fn get_day_number(&self) -> usize {
         11
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.stones = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.stones)
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            // No published answer for part two
+            Example::new(DEFAULT_EXAMPLE, Some("55312"), None),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.stones = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.stones).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.stones)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.stones).into())
     }
 }
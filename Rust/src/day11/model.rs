@@ -29,7 +29,7 @@ impl Stone {
         }
 
         // Second rule, even length number stones are split in two
-        if (self.value.ilog10() + 1) % 2 == 0 {
+        if (self.value.ilog10() + 1).is_multiple_of(2) {
             return self.split();
         }
 
@@ -43,7 +43,7 @@ impl Stone {
     /// Example:
     /// Stone{XXXYYY} => Stone{XXX} and Stone{YYY}
     fn split(&self) -> Vec<Self> {
-        let half_length: u32 = (self.value.ilog10() + 1) / 2;
+        let half_length: u32 = self.value.ilog10().div_ceil(2);
 
         vec![
             self.with_value(self.value / (10_u128.pow(half_length))),
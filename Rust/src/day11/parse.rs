@@ -5,10 +5,20 @@ use nom::multi::separated_list1;
 use nom::Parser;
 use std::num::ParseIntError;
 
-pub fn parse_input(input: &str) -> Vec<Stone> {
-    input
-        .trim()
-        .split(' ')
-        .map(|str_val: &str| Stone::new(str_val.parse::<u128>().unwrap()))
-        .collect()
+use crate::error::AocError;
+
+pub fn parse_input(input: &str) -> Result<Vec<Stone>, AocError> {
+    let mut stones: Vec<Stone> = Vec::new();
+    let mut column: usize = 1;
+
+    for str_val in input.trim().split(' ') {
+        let value: u128 = str_val.parse::<u128>().map_err(|error| {
+            AocError::parse(1, column, format!("Invalid stone '{str_val}' ({error})"))
+        })?;
+        stones.push(Stone::new(value));
+
+        column += str_val.len() + 1;
+    }
+
+    Ok(stones)
 }
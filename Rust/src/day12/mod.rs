@@ -1,8 +1,10 @@
 mod model;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day12::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::Grid;
 
 #[derive(Default)]
@@ -10,17 +12,33 @@ pub struct Day12 {
     garden: Grid<char>,
 }
 
+register_solution!(Day12);
+
 impl Aoc24Solution for Day12 {
     fn get_day_number(&self) -> usize {
         12
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.garden = Grid::from_string(&self.get_data(is_test));
-        solve_part_one(&self.garden) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(DEFAULT_EXAMPLE, Some("1930"), Some("1206")),
+            Example::new("small", Some("140"), Some("80")),
+            Example::new("nested", Some("772"), Some("436")),
+            Example::new("e-shape", None, Some("236")),
+            Example::new("diagonal", None, Some("368")),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.garden, _) = Grid::parse_with(input, &[], Some)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.garden).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.garden) as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.garden).into())
     }
 }
@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day13::model::ClawMachine;
 use crate::day13::parse::parse_input;
 use crate::day13::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::maths::extended_euclidean_algorithm;
 
 #[derive(Default)]
@@ -13,17 +15,30 @@ pub struct Day13 {
     claw_machines: Vec<ClawMachine>,
 }
 
+register_solution!(Day13);
+
 impl Aoc24Solution for Day13 {
     fn get_day_number(&self) -> usize {
         13
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.claw_machines = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.claw_machines) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            // No published answer for part two
+            Example::new(DEFAULT_EXAMPLE, Some("480"), None),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.claw_machines = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.claw_machines).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.claw_machines) as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.claw_machines).into())
     }
 }
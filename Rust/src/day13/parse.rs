@@ -9,26 +9,42 @@ use nom::sequence::{separated_pair, tuple};
 use nom::{IResult, Parser};
 
 use crate::day13::model::ClawMachine;
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
 
-pub fn parse_input(input: &str) -> Vec<ClawMachine> {
+type IndexedLine<'a> = (usize, &'a str);
+
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachine>, AocError> {
     let mut machines: Vec<ClawMachine> = Vec::with_capacity(input.len() / 4);
+    let line_count: usize = input.lines().count();
+
+    for mut chunk in &input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .chunks(3)
+    {
+        let missing_line = || AocError::parse(line_count + 1, 1, "Incomplete claw machine");
 
-    for mut chunk in &input.lines().filter(|line| !line.is_empty()).chunks(3) {
-        let line_a: &str = chunk.next().expect("Chunk error, no line 0");
-        let line_b: &str = chunk.next().expect("Chunk error, no line 1");
-        let line_p: &str = chunk.next().expect("Chunk error, no line 2");
-        machines.push(parse_claw_machine(line_a, line_b, line_p))
+        let line_a: IndexedLine = chunk.next().ok_or_else(missing_line)?;
+        let line_b: IndexedLine = chunk.next().ok_or_else(missing_line)?;
+        let line_p: IndexedLine = chunk.next().ok_or_else(missing_line)?;
+        machines.push(parse_claw_machine(line_a, line_b, line_p)?)
     }
 
-    machines
+    Ok(machines)
 }
 
-fn parse_claw_machine(line_a: &str, line_b: &str, line_p: &str) -> ClawMachine {
-    let (_, button_a) = parse_button(line_a).expect("Error on button A");
-    let (_, button_b) = parse_button(line_b).expect("Error on button B");
-    let (_, prize) = parse_prize(line_p).expect("Error on prize");
+fn parse_claw_machine(
+    (index_a, line_a): IndexedLine,
+    (index_b, line_b): IndexedLine,
+    (index_p, line_p): IndexedLine,
+) -> Result<ClawMachine, AocError> {
+    let button_a = parse_line_with(index_a, line_a, parse_button)?;
+    let button_b = parse_line_with(index_b, line_b, parse_button)?;
+    let prize = parse_line_with(index_p, line_p, parse_prize)?;
 
-    ClawMachine::new(button_a, button_b, prize)
+    Ok(ClawMachine::new(button_a, button_b, prize))
 }
 
 fn parse_button(line: &str) -> IResult<&str, (u64, u64)> {
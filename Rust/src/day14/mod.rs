@@ -2,27 +2,51 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Param, Params, DEFAULT_EXAMPLE};
 use crate::day14::model::SecurityRobot;
 use crate::day14::parse::parser_input;
 use crate::day14::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day14 {
     security_robots: Vec<SecurityRobot>,
 }
 
+register_solution!(Day14);
+
 impl Aoc24Solution for Day14 {
     fn get_day_number(&self) -> usize {
         14
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.security_robots = parser_input(&self.get_data(is_test));
-        solve_part_one(&self.security_robots) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            // The picture of part two only appears with the real input
+            Example::new(DEFAULT_EXAMPLE, Some("12"), None),
+        ]
+    }
+
+    fn get_params(&self) -> Vec<Param> {
+        vec![
+            Param::new("width", 101, 11).at_least(1),
+            Param::new("height", 103, 7).at_least(1),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.security_robots = parser_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, params: &Params) -> Result<Answer, AocError> {
+        let (width, height): (i32, i32) = (params.get("width")?, params.get("height")?);
+        Ok(solve_part_one(&self.security_robots, width, height).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.security_robots) as i128
+    fn solve_part_two(&self, params: &Params) -> Result<Answer, AocError> {
+        let (width, height): (i32, i32) = (params.get("width")?, params.get("height")?);
+        Ok(solve_part_two(&self.security_robots, width, height).into())
     }
 }
@@ -1,12 +1,18 @@
 use crate::day14::model::SecurityRobot;
+use crate::error::AocError;
 use crate::toolbox::parsing::parse_numbers;
 
-pub fn parser_input(input: &str) -> Vec<SecurityRobot> {
+pub fn parser_input(input: &str) -> Result<Vec<SecurityRobot>, AocError> {
     input
         .lines()
-        .map(|line| {
-            let numbers = parse_numbers(line);
-            SecurityRobot::new(numbers[0], numbers[1], numbers[2], numbers[3])
+        .enumerate()
+        .map(|(line_index, line)| match parse_numbers(line)[..] {
+            [x0, y0, vx, vy] => Ok(SecurityRobot::new(x0, y0, vx, vy)),
+            _ => Err(AocError::parse(
+                line_index + 1,
+                1,
+                "Expected a position and a speed",
+            )),
         })
         .collect()
 }
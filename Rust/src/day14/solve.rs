@@ -1,24 +1,23 @@
 use crate::day14::model::SecurityRobot;
-use crate::toolbox::Coordinates;
+use crate::record_frame;
+use crate::toolbox::{Coordinates, Grid, RenderTile, Scene};
 use itertools::all;
+use log::debug;
 use std::collections::HashSet;
 
 // Part one
 const ELAPSED_TIME_P1: i32 = 100;
 
-const X_AXIS_LENGTH: i32 = 101; // Input: 101, test: 11
-const Y_AXIS_LENGTH: i32 = 103; // Input: 103, test: 07
-
-pub fn solve_part_one(robots: &[SecurityRobot]) -> i32 {
-    let x_half_length: i32 = X_AXIS_LENGTH / 2;
-    let y_half_length: i32 = Y_AXIS_LENGTH / 2;
+pub fn solve_part_one(robots: &[SecurityRobot], width: i32, height: i32) -> i32 {
+    let x_half_length: i32 = width / 2;
+    let y_half_length: i32 = height / 2;
 
     let accumulators: (i32, i32, i32, i32) = robots
         .iter()
         .map(|robot| robot.progress_for(ELAPSED_TIME_P1))
         .map(|coord| Coordinates {
-            x: ((coord.x % X_AXIS_LENGTH) + X_AXIS_LENGTH) % X_AXIS_LENGTH,
-            y: ((coord.y % Y_AXIS_LENGTH) + Y_AXIS_LENGTH) % Y_AXIS_LENGTH,
+            x: ((coord.x % width) + width) % width,
+            y: ((coord.y % height) + height) % height,
         })
         .fold((0, 0, 0, 0), |(acc0, acc1, acc2, acc3), value| {
             if value.x < x_half_length && value.y < y_half_length {
@@ -43,21 +42,29 @@ pub fn solve_part_one(robots: &[SecurityRobot]) -> i32 {
 /// This means that most of the robots are gathered.
 ///
 /// This functions look for the first time the robot are highly condensed.
-pub fn solve_part_two(robots: &[SecurityRobot]) -> i32 {
-    let x_quarter_length: i32 = X_AXIS_LENGTH / 4;
-    let y_quarter_length: i32 = Y_AXIS_LENGTH / 4;
+///
+/// Positions repeat after `width * height` seconds, robots not gathered by then never will, as
+/// with the example which is too small for the picture. Without any robot, there is no picture.
+pub fn solve_part_two(robots: &[SecurityRobot], width: i32, height: i32) -> Option<i32> {
+    if robots.is_empty() {
+        return None;
+    }
 
-    let mut time: i32 = 0;
-    loop {
+    let x_quarter_length: i32 = width / 4;
+    let y_quarter_length: i32 = height / 4;
+
+    for time in 0..(width * height) {
         let robot_coordinates: Vec<Coordinates> = robots
             .iter()
             .map(|robot| robot.progress_for(time))
             .map(|coord| Coordinates {
-                x: ((coord.x % X_AXIS_LENGTH) + X_AXIS_LENGTH) % X_AXIS_LENGTH,
-                y: ((coord.y % Y_AXIS_LENGTH) + Y_AXIS_LENGTH) % Y_AXIS_LENGTH,
+                x: ((coord.x % width) + width) % width,
+                y: ((coord.y % height) + height) % height,
             })
             .collect();
 
+        record_frame!(Scene::new(&robots_grid(&robot_coordinates, width, height)));
+
         let len: i32 = robot_coordinates.len() as i32;
 
         // Compute average position
@@ -77,33 +84,39 @@ pub fn solve_part_two(robots: &[SecurityRobot]) -> i32 {
             / len;
 
         if variance_x <= x_quarter_length.pow(2) && variance_y <= y_quarter_length.pow(2) {
-            display_robots(robot_coordinates);
-            break;
-        } else {
-            time += 1
+            debug!(
+                "Robots at time {time}:\n{}",
+                display_robots(&robot_coordinates, width, height)
+            );
+            return Some(time);
         }
     }
 
-    time
+    None
 }
 
-pub fn display_robots(robot_coordinates: Vec<Coordinates>) {
-    let mut debug_grid = vec![vec![0; Y_AXIS_LENGTH as usize]; X_AXIS_LENGTH as usize];
+/// Robots of each tile, a `.` standing for no robot.
+///
+/// Robots move along x horizontally, so that the picture is drawn upright.
+pub fn robots_grid(robot_coordinates: &[Coordinates], width: i32, height: i32) -> Grid<char> {
+    let mut counts: Grid<u32> = Grid::from_vec(
+        vec![0; (width * height) as usize],
+        height as usize,
+        width as usize,
+    );
     for coord in robot_coordinates.iter() {
-        debug_grid[coord.x as usize][coord.y as usize] += 1;
+        counts[Coordinates {
+            x: coord.y,
+            y: coord.x,
+        }] += 1;
     }
 
-    let mut grid_str = String::new();
-    for i in 0..debug_grid.len() {
-        for j in 0..debug_grid.first().unwrap().len() {
-            let val = debug_grid[i][j];
-            let string: String = match val {
-                0 => String::from("."),
-                _ => val.to_string(),
-            };
-            grid_str.push_str(&string);
-        }
-        grid_str.push('\n')
-    }
-    println!("{}", grid_str);
+    counts.map(|count| match count {
+        0 => '.',
+        _ => count.glyph(),
+    })
+}
+
+pub fn display_robots(robot_coordinates: &[Coordinates], width: i32, height: i32) -> String {
+    Scene::new(&robots_grid(robot_coordinates, width, height)).to_text()
 }
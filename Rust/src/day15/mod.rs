@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day15::model::Tile15;
 use crate::day15::parse::parse_input;
 use crate::day15::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Direction, Grid};
 
 #[derive(Default)]
@@ -15,18 +17,30 @@ pub struct Day15 {
     robot_position: Coordinates,
 }
 
+register_solution!(Day15);
+
 impl Aoc24Solution for Day15 {
     fn get_day_number(&self) -> usize {
         15
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.warehouse, self.robot_position, self.instructions) =
-            parse_input(&self.get_data(is_test));
-        solve_part_one(&self.warehouse, &self.robot_position, &self.instructions) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(DEFAULT_EXAMPLE, Some("10092"), Some("9021")),
+            Example::new("small", Some("2028"), None),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.warehouse, self.robot_position, self.instructions) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.warehouse, &self.robot_position, &self.instructions).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.warehouse, &self.robot_position, &self.instructions) as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.warehouse, &self.robot_position, &self.instructions).into())
     }
 }
@@ -1,16 +1,16 @@
 use std::cmp::PartialEq;
 use std::collections::{HashSet, VecDeque};
-use std::fmt::{Debug, Formatter};
 
 use itertools::Itertools;
+use log::debug;
 
-use crate::day15::parse::GRID_SIZE;
+use crate::toolbox::render::{RenderTile, Rgb};
 use crate::toolbox::{Coordinates, Direction, Grid};
 
 const X_WEIGHT: i32 = 100;
 const Y_WEIGHT: i32 = 1;
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Tile15 {
     #[default]
     Empty,
@@ -24,14 +24,22 @@ pub enum Tile15 {
     BoxRight,
 }
 
-impl Debug for Tile15 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile15 {
+    fn glyph(&self) -> char {
         match self {
-            Self::Box => write!(f, "0"),
-            Self::Empty => write!(f, "."),
-            Self::Wall => write!(f, "#"),
-            Self::BoxLeft => write!(f, "["),
-            Self::BoxRight => write!(f, "]"),
+            Self::Box => '0',
+            Self::Empty => '.',
+            Self::Wall => '#',
+            Self::BoxLeft => '[',
+            Self::BoxRight => ']',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Self::Box | Self::BoxLeft | Self::BoxRight => Rgb::YELLOW,
+            Self::Empty => Rgb::WHITE,
+            Self::Wall => Rgb::DARK_GREY,
         }
     }
 }
@@ -70,14 +78,17 @@ impl Grid<Tile15> {
     ) {
         if nb_boxes > 0 {
             // The new robot position becomes empty
-            self.data[new_robot_pos.x as usize][new_robot_pos.y as usize] = Tile15::Empty;
+            self[*new_robot_pos] = Tile15::Empty;
 
             // And a box "appears" at the end of the box chain
             let delta: (i32, i32) = direction.get_delta();
             let new_box_x: i32 = new_robot_pos.x + (nb_boxes as i32) * delta.0;
             let new_box_y: i32 = new_robot_pos.y + (nb_boxes as i32) * delta.1;
 
-            self.data[new_box_x as usize][new_box_y as usize] = Tile15::Box;
+            self[Coordinates {
+                x: new_box_x,
+                y: new_box_y,
+            }] = Tile15::Box;
         }
     }
 
@@ -85,28 +96,12 @@ impl Grid<Tile15> {
 
     /// Create an expanded copy of the current grid.
     pub fn build_expanded(&self) -> Grid<Tile15> {
-        let mut expanded_data: Vec<Vec<Tile15>> = Vec::with_capacity(GRID_SIZE * 2);
-
-        for i in 0..self.depth() {
-            let mut expanded_line: Vec<Tile15> = Vec::with_capacity(GRID_SIZE * 2);
-
-            for j in 0..self.width() {
-                let new_tiles: Vec<Tile15> = match self.get_ref(&Coordinates {
-                    x: i as i32,
-                    y: j as i32,
-                }) {
-                    Some(Tile15::Empty) => vec![Tile15::Empty; 2],
-                    Some(Tile15::Wall) => vec![Tile15::Wall; 2],
-                    Some(Tile15::Box) => vec![Tile15::BoxLeft, Tile15::BoxRight],
-                    _ => unreachable!("Error while expanding"),
-                };
-                expanded_line.extend(new_tiles);
-            }
-
-            expanded_data.push(expanded_line);
-        }
-
-        Grid::new(expanded_data)
+        self.expand_with(1, 2, |tile| match tile {
+            Tile15::Empty => vec![Tile15::Empty; 2],
+            Tile15::Wall => vec![Tile15::Wall; 2],
+            Tile15::Box => vec![Tile15::BoxLeft, Tile15::BoxRight],
+            _ => unreachable!("Error while expanding"),
+        })
     }
 
     // To keep the easy push algorithm from part one:
@@ -179,7 +174,7 @@ impl Grid<Tile15> {
     ) {
         if nb_boxes > 0 {
             // The new robot position becomes empty
-            self.data[new_robot_pos.x as usize][new_robot_pos.y as usize] = Tile15::Empty;
+            self[*new_robot_pos] = Tile15::Empty;
 
             let delta: (i32, i32) = direction.get_delta();
 
@@ -188,8 +183,12 @@ impl Grid<Tile15> {
                 let new_box_x: i32 = new_robot_pos.x + (i as i32) * delta.0;
                 let new_box_y: i32 = new_robot_pos.y + (i as i32) * delta.1;
 
-                let cur_tile: &Tile15 = &self.data[new_box_x as usize][new_box_y as usize];
-                self.data[new_box_x as usize][new_box_y as usize] = match cur_tile {
+                let box_coord: Coordinates = Coordinates {
+                    x: new_box_x,
+                    y: new_box_y,
+                };
+                let cur_tile: &Tile15 = &self[box_coord];
+                self[box_coord] = match cur_tile {
                     Tile15::BoxLeft => Tile15::BoxRight, // Pushing to the right
                     Tile15::BoxRight => Tile15::BoxLeft, // Pushing to the left
                     Tile15::Empty => Tile15::Empty,
@@ -206,7 +205,10 @@ impl Grid<Tile15> {
             let new_box_x: i32 = new_robot_pos.x + (nb_boxes as i32) * 2 * delta.0;
             let new_box_y: i32 = new_robot_pos.y + (nb_boxes as i32) * 2 * delta.1;
 
-            self.data[new_box_x as usize][new_box_y as usize] = match direction {
+            self[Coordinates {
+                x: new_box_x,
+                y: new_box_y,
+            }] = match direction {
                 Direction::E => Tile15::BoxRight, // Pushing to the right
                 Direction::W => Tile15::BoxLeft,  // Pushing to the left
                 _ => unreachable!("Invalid horizontal direction {direction:?}"),
@@ -304,15 +306,12 @@ impl Grid<Tile15> {
                 box_coord.into_iter().sorted_by_key(sort_function).collect();
 
             // Move boxes
-            let data_copy = self.data.clone();
+            let grid_copy: Grid<Tile15> = self.clone();
             for box_coord in sorted_box_coord.iter() {
                 let target_coord: Coordinates = box_coord.step(direction);
 
                 self.replace(box_coord, Tile15::Empty);
-                self.replace(
-                    &target_coord,
-                    data_copy[box_coord.x as usize][box_coord.y as usize].clone(),
-                );
+                self.replace(&target_coord, grid_copy[*box_coord].clone());
             }
         }
     }
@@ -340,11 +339,7 @@ impl Grid<Tile15> {
 
     /// Guess what, I managed to make boxes disappear.
     pub fn count_boxes(&self) -> usize {
-        self.data
-            .iter()
-            .flatten()
-            .filter(|tile| Tile15::BoxLeft == **tile)
-            .count()
+        self.iter().filter(|tile| Tile15::BoxLeft == **tile).count()
     }
 
     /// I might also have broken one or two boxes...
@@ -361,13 +356,13 @@ impl Grid<Tile15> {
                     && self.get_ref(&current_coord.step(&Direction::E)).unwrap()
                         != &Tile15::BoxRight
                 {
-                    println!("Invalid at {current_coord:?}");
+                    debug!("Invalid at {current_coord:?}");
                     return false;
                 }
                 if current_value == &Tile15::BoxRight
                     && self.get_ref(&current_coord.step(&Direction::W)).unwrap() != &Tile15::BoxLeft
                 {
-                    println!("Invalid at {current_coord:?}");
+                    debug!("Invalid at {current_coord:?}");
                     return false;
                 }
             }
@@ -1,55 +1,61 @@
 use crate::day15::model::Tile15;
-use crate::toolbox::{Coordinates, Direction, Grid};
+use crate::error::AocError;
+use crate::toolbox::parsing::unexpected_char;
+use crate::toolbox::{Coordinates, Direction, FromGridChar, Grid, Markers};
+
+const ROBOT: char = '@';
+
+impl FromGridChar for Tile15 {
+    fn from_grid_char(chr: char) -> Option<Self> {
+        match chr {
+            '.' | ROBOT => Some(Tile15::Empty), // Robot is managed outside
+            'O' => Some(Tile15::Box),
+            '#' => Some(Tile15::Wall),
+            _ => None,
+        }
+    }
+}
 
-pub const GRID_SIZE: usize = 50; // From input
+pub fn parse_input(input: &str) -> Result<(Grid<Tile15>, Coordinates, Vec<Direction>), AocError> {
+    let input_parts: Vec<&str> = input.split("\n\n").collect();
+    if input_parts.len() != 2 {
+        return Err(AocError::invalid_state(
+            "Expected a warehouse and a list of moves, separated by an empty line",
+        ));
+    }
 
-pub fn parse_input(input: &str) -> (Grid<Tile15>, Coordinates, Vec<Direction>) {
-    let input_parts: Vec<&str> = input.split("\r\n\r\n").collect(); // Windows windows windows ...
-    let (grid, robot_pos) = parse_grid(input_parts[0]);
-    let directions = parse_directions(input_parts[1]);
+    let (grid, robot_pos) = parse_grid(input_parts[0])?;
+    // Moves start after the warehouse and the empty line
+    let directions = parse_directions(input_parts[1], grid.depth() + 1)?;
 
-    (grid, robot_pos, directions)
+    Ok((grid, robot_pos, directions))
 }
 
-fn parse_grid(grid_lines: &str) -> (Grid<Tile15>, Coordinates) {
-    let mut data: Vec<Vec<Tile15>> = Vec::with_capacity(GRID_SIZE);
-    let mut robot_pos: Option<Coordinates> = None;
-
-    for line in grid_lines.lines() {
-        let mut data_line: Vec<Tile15> = Vec::with_capacity(GRID_SIZE);
-
-        for chr in line.chars() {
-            match chr {
-                '.' => data_line.push(Tile15::Empty),
-                'O' => data_line.push(Tile15::Box),
-                '#' => data_line.push(Tile15::Wall),
-                '@' => {
-                    robot_pos = Some(Coordinates {
-                        x: data.len() as i32,
-                        y: data_line.len() as i32,
-                    });
-                    data_line.push(Tile15::Empty) // Robot is managed outside
-                }
-                _ => unreachable!(),
-            }
-        }
-        data.push(data_line)
-    }
-
-    (Grid::new(data), robot_pos.expect("Found no robot in input"))
+fn parse_grid(grid_lines: &str) -> Result<(Grid<Tile15>, Coordinates), AocError> {
+    let (grid, markers): (Grid<Tile15>, Markers) = Grid::parse(grid_lines, &[ROBOT])?;
+    Ok((grid, markers.single(ROBOT)?))
 }
 
-fn parse_directions(dir_lines: &str) -> Vec<Direction> {
-    dir_lines
-        .lines()
-        .flat_map(|line| {
-            line.chars().map(|chr| match chr {
+fn parse_directions(dir_lines: &str, first_line_index: usize) -> Result<Vec<Direction>, AocError> {
+    let mut directions: Vec<Direction> = Vec::with_capacity(dir_lines.len());
+
+    for (line_index, line) in dir_lines.lines().enumerate() {
+        for (chr_index, chr) in line.chars().enumerate() {
+            directions.push(match chr {
                 '^' => Direction::N,
                 '>' => Direction::E,
                 'v' => Direction::S,
                 '<' => Direction::W,
-                _ => unreachable!(),
-            })
-        })
-        .collect()
+                _ => {
+                    return Err(unexpected_char(
+                        first_line_index + line_index,
+                        chr_index,
+                        chr,
+                    ))
+                }
+            });
+        }
+    }
+
+    Ok(directions)
 }
@@ -1,5 +1,6 @@
 use crate::day15::model::Tile15;
-use crate::toolbox::{Coordinates, Direction, Grid};
+use crate::record_frame;
+use crate::toolbox::{Coordinates, Direction, Grid, Scene};
 
 pub fn solve_part_one(
     grid: &Grid<Tile15>,
@@ -18,6 +19,8 @@ pub fn solve_part_one(
                 grid.push_boxes_p1(&current_robot_position, dir, nb_boxes); // THEN push the box(es)
             }
         }
+
+        record_frame!(Scene::new(&grid).mark(current_robot_position, '@'));
     }
 
     grid.compute_score()
@@ -45,6 +48,8 @@ pub fn solve_part_two(
                 // THEN push the box(es)
             }
         }
+
+        record_frame!(Scene::new(&expanded_grid).mark(current_robot_position, '@'));
     }
 
     expanded_grid.compute_score()
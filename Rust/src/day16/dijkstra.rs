@@ -1,7 +1,8 @@
 use crate::day16::model::Tile16;
 use crate::day16::solve::{STARTING_DIR, STEP_COST, TURN_COST};
-use crate::toolbox::{Coordinates, Direction, Grid};
+use crate::toolbox::{Coordinates, Direction, Grid, Scene};
 use itertools::Itertools;
+use log::debug;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::hash::Hash;
@@ -224,7 +225,12 @@ pub fn custom_dijkstra(
 
     let part_two_result: usize = path_coordinates.len();
 
-    // maze.show_path(&path_coordinates.into_iter().collect_vec(), 'O');
+    debug!(
+        "Tiles on a best path:\n{}",
+        Scene::new(maze)
+            .path(&path_coordinates.iter().copied().collect_vec(), 'O')
+            .to_ansi()
+    );
 
     // --- Return ---
     (part_one_result, part_two_result as i64)
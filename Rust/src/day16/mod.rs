@@ -3,10 +3,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day16::dijkstra::custom_dijkstra;
 use crate::day16::model::Tile16;
 use crate::day16::parse::parse_input;
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Grid};
 
 #[derive(Default)]
@@ -15,26 +17,37 @@ pub struct Day16 {
     start_coordinates: Coordinates,
     exit_coordinates: Coordinates,
 
-    // Special case where both results are computed at the same time
-    part_one_result: i64,
-    part_two_result: i64,
+    // Both answers come from the same search, run once while parsing
+    lowest_score: i64,
+    best_seats: i64,
 }
 
+register_solution!(Day16);
+
 impl Aoc24Solution for Day16 {
     fn get_day_number(&self) -> usize {
         16
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.maze, self.start_coordinates, self.exit_coordinates) =
-            parse_input(&self.get_data(is_test));
-        (self.part_one_result, self.part_two_result) =
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(DEFAULT_EXAMPLE, Some("7036"), Some("45")),
+            Example::new("second", Some("11048"), Some("64")),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.maze, self.start_coordinates, self.exit_coordinates) = parse_input(input)?;
+        (self.lowest_score, self.best_seats) =
             custom_dijkstra(&self.maze, &self.start_coordinates, &self.exit_coordinates);
+        Ok(())
+    }
 
-        self.part_one_result as i128
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(self.lowest_score.into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        self.part_two_result as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(self.best_seats.into())
     }
 }
@@ -1,17 +1,24 @@
-use std::fmt::{Debug, Formatter};
+use crate::toolbox::render::{RenderTile, Rgb};
 
-#[derive(Default, Eq, PartialEq)]
+#[derive(Debug, Default, Eq, PartialEq)]
 pub enum Tile16 {
     #[default]
     Empty,
     Wall,
 }
 
-impl Debug for Tile16 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile16 {
+    fn glyph(&self) -> char {
         match self {
-            Tile16::Empty => write!(f, "."),
-            Tile16::Wall => write!(f, "#"),
+            Tile16::Empty => '.',
+            Tile16::Wall => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile16::Empty => Rgb::WHITE,
+            Tile16::Wall => Rgb::DARK_GREY,
         }
     }
 }
@@ -3,10 +3,12 @@ mod parse;
 mod reversed;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params};
 use crate::day17::model::Computer;
 use crate::day17::parse::parse_input;
 use crate::day17::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Grid};
 
 #[derive(Default)]
@@ -14,21 +16,30 @@ pub struct Day17 {
     computer: Computer,
 }
 
+register_solution!(Day17);
+
 impl Aoc24Solution for Day17 {
     fn get_day_number(&self) -> usize {
         17
     }
 
-    fn get_extra_name(&self) -> Option<&str> {
-        Some("2")
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("1", Some("4,6,3,5,6,3,5,2,1,0"), None),
+            Example::new("2", Some("5,7,3,0"), None),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.computer = parse_input(input)?;
+        Ok(())
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.computer = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.computer)
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.computer)?.into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.computer)
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.computer)?.into())
     }
 }
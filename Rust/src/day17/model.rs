@@ -1,3 +1,7 @@
+use log::debug;
+
+use crate::error::AocError;
+
 #[derive(Clone, Debug)]
 pub enum Instruction {
     Adv,
@@ -11,17 +15,19 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn from_opcode(op_code: u8) -> Self {
+    pub fn from_opcode(op_code: u8) -> Result<Self, AocError> {
         match op_code {
-            0 => Self::Adv,
-            1 => Self::Bxl,
-            2 => Self::Bst,
-            3 => Self::Jnz,
-            4 => Self::Bxc,
-            5 => Self::Out,
-            6 => Self::Bdv,
-            7 => Self::Cdv,
-            _ => unreachable!("Invalid opcode '{op_code}"),
+            0 => Ok(Self::Adv),
+            1 => Ok(Self::Bxl),
+            2 => Ok(Self::Bst),
+            3 => Ok(Self::Jnz),
+            4 => Ok(Self::Bxc),
+            5 => Ok(Self::Out),
+            6 => Ok(Self::Bdv),
+            7 => Ok(Self::Cdv),
+            _ => Err(AocError::invalid_state(format!(
+                "Invalid opcode '{op_code}'"
+            ))),
         }
     }
 }
@@ -68,15 +74,22 @@ impl Computer {
 
     // Run methods
 
-    pub fn run(&mut self) -> Vec<u8> {
+    pub fn run(&mut self) -> Result<Vec<u8>, AocError> {
         while self.stack_pointer < self.stack.len() {
             // Reset jump flag
             self.jump_flag = true;
 
             // Run next instruction
-            let instruction: Instruction = Instruction::from_opcode(self.stack[self.stack_pointer]);
-            let operand: u8 = self.stack[self.stack_pointer + 1];
-            self.run_instruction(&instruction, operand);
+            let instruction: Instruction =
+                Instruction::from_opcode(self.stack[self.stack_pointer])?;
+            let operand: u8 =
+                *self
+                    .stack
+                    .get(self.stack_pointer + 1)
+                    .ok_or(AocError::invalid_state(
+                        "Program ends with an instruction without operand",
+                    ))?;
+            self.run_instruction(&instruction, operand)?;
 
             // Continue to next instruction
             if self.jump_flag {
@@ -84,7 +97,7 @@ impl Computer {
             }
         }
 
-        self.stdout.clone()
+        Ok(self.stdout.clone())
     }
 
     pub fn format_stdout(stdout: &[u8]) -> String {
@@ -95,25 +108,26 @@ impl Computer {
     // Decompiler methods
 
     #[allow(dead_code)]
-    pub fn decompile(&self) {
-        println!(">> {:?}", self.stack);
-        println!("START");
+    pub fn decompile(&self) -> Result<(), AocError> {
+        debug!(">> {:?}", self.stack);
+        debug!("START");
         for pointer in 0..(self.stack.len() / 2) {
-            let instruction: Instruction = Instruction::from_opcode(self.stack[2 * pointer]);
+            let instruction: Instruction = Instruction::from_opcode(self.stack[2 * pointer])?;
             let operand: u8 = self.stack[2 * pointer + 1];
 
             match instruction {
-                Instruction::Adv => println!("A = A >> {}", Computer::decompile_combo(&operand)),
-                Instruction::Bxl => println!("B = B ^ {operand}"),
-                Instruction::Bst => println!("B = {} & 7", Computer::decompile_combo(&operand)),
-                Instruction::Jnz => println!("<jump to {operand}>",),
-                Instruction::Bxc => println!("B = B ^ C"),
-                Instruction::Out => println!("print({})", Computer::decompile_combo(&operand)),
-                Instruction::Bdv => println!("B = A >> {}", Computer::decompile_combo(&operand)),
-                Instruction::Cdv => println!("C = A >> {}", Computer::decompile_combo(&operand)),
+                Instruction::Adv => debug!("A = A >> {}", Computer::decompile_combo(&operand)),
+                Instruction::Bxl => debug!("B = B ^ {operand}"),
+                Instruction::Bst => debug!("B = {} & 7", Computer::decompile_combo(&operand)),
+                Instruction::Jnz => debug!("<jump to {operand}>",),
+                Instruction::Bxc => debug!("B = B ^ C"),
+                Instruction::Out => debug!("print({})", Computer::decompile_combo(&operand)),
+                Instruction::Bdv => debug!("B = A >> {}", Computer::decompile_combo(&operand)),
+                Instruction::Cdv => debug!("C = A >> {}", Computer::decompile_combo(&operand)),
             };
         }
-        println!("END");
+        debug!("END");
+        Ok(())
     }
 
     #[allow(dead_code)]
@@ -130,34 +144,38 @@ impl Computer {
 
     // Instructions
 
-    fn run_instruction(&mut self, instruction: &Instruction, operand: u8) {
+    fn run_instruction(&mut self, instruction: &Instruction, operand: u8) -> Result<(), AocError> {
         match instruction {
-            Instruction::Adv => self.run_adv(operand),
+            Instruction::Adv => self.run_adv(operand)?,
             Instruction::Bxl => self.run_bxl(operand),
-            Instruction::Bst => self.run_bst(operand),
+            Instruction::Bst => self.run_bst(operand)?,
             Instruction::Jnz => self.run_jnz(operand),
             Instruction::Bxc => self.run_bxc(operand),
-            Instruction::Out => self.run_out(operand),
-            Instruction::Bdv => self.run_bdv(operand),
-            Instruction::Cdv => self.run_cdv(operand),
+            Instruction::Out => self.run_out(operand)?,
+            Instruction::Bdv => self.run_bdv(operand)?,
+            Instruction::Cdv => self.run_cdv(operand)?,
         }
+        Ok(())
     }
 
-    fn resolve_combo(&self, combo: u8) -> u32 {
+    fn resolve_combo(&self, combo: u8) -> Result<u32, AocError> {
         match combo {
-            0..=3 => combo as u32,
-            4 => self.a,
-            5 => self.b,
-            6 => self.c,
-            7 => unimplemented!("Combo code 7 is reserved"),
-            _ => unreachable!("Invalid combo value '{combo}'"),
+            0..=3 => Ok(combo as u32),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            7 => Err(AocError::invalid_state("Combo code 7 is reserved")),
+            _ => Err(AocError::invalid_state(format!(
+                "Invalid combo value '{combo}'"
+            ))),
         }
     }
 
     /// Division (opcode=0)
     #[inline]
-    fn run_adv(&mut self, operand: u8) {
-        self.a >>= self.resolve_combo(operand)
+    fn run_adv(&mut self, operand: u8) -> Result<(), AocError> {
+        self.a >>= self.resolve_combo(operand)?;
+        Ok(())
     }
 
     /// Bitwise XOR (opcode=1)
@@ -168,8 +186,9 @@ impl Computer {
 
     /// Modulo (opcode=2)
     #[inline]
-    fn run_bst(&mut self, operand: u8) {
-        self.b = self.resolve_combo(operand) & 7
+    fn run_bst(&mut self, operand: u8) -> Result<(), AocError> {
+        self.b = self.resolve_combo(operand)? & 7;
+        Ok(())
     }
 
     /// Jump (opcode=3)
@@ -191,19 +210,22 @@ impl Computer {
 
     /// Write to stdout (opcode=5)
     #[inline]
-    fn run_out(&mut self, operand: u8) {
-        self.stdout.push((self.resolve_combo(operand) & 7) as u8);
+    fn run_out(&mut self, operand: u8) -> Result<(), AocError> {
+        self.stdout.push((self.resolve_combo(operand)? & 7) as u8);
+        Ok(())
     }
 
     /// Division (opcode=6)
     #[inline]
-    fn run_bdv(&mut self, operand: u8) {
-        self.b = self.a >> self.resolve_combo(operand)
+    fn run_bdv(&mut self, operand: u8) -> Result<(), AocError> {
+        self.b = self.a >> self.resolve_combo(operand)?;
+        Ok(())
     }
 
     /// Division (opcode=7)
     #[inline]
-    fn run_cdv(&mut self, operand: u8) {
-        self.c = self.a >> self.resolve_combo(operand)
+    fn run_cdv(&mut self, operand: u8) -> Result<(), AocError> {
+        self.c = self.a >> self.resolve_combo(operand)?;
+        Ok(())
     }
 }
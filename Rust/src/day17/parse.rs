@@ -1,4 +1,6 @@
 use crate::day17::model::Computer;
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{u32 as u32_parser, u8 as u8_parser};
@@ -8,16 +10,19 @@ use nom::sequence::tuple;
 use nom::IResult;
 use std::io::ErrorKind;
 
-pub fn parse_input(input: &str) -> Computer {
+pub fn parse_input(input: &str) -> Result<Computer, AocError> {
     let lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 5 {
+        return Err(AocError::parse(lines.len() + 1, 1, "Missing lines"));
+    }
 
-    let (_, reg_a) = parse_registry(lines[0]).unwrap();
-    let (_, reg_b) = parse_registry(lines[1]).unwrap();
-    let (_, reg_c) = parse_registry(lines[2]).unwrap();
+    let reg_a = parse_line_with(0, lines[0], parse_registry)?;
+    let reg_b = parse_line_with(1, lines[1], parse_registry)?;
+    let reg_c = parse_line_with(2, lines[2], parse_registry)?;
     // Empty line
-    let (_, program) = parse_program(lines[4]).unwrap();
+    let program = parse_line_with(4, lines[4], parse_program)?;
 
-    Computer::new(reg_a, reg_b, reg_c, program)
+    Ok(Computer::new(reg_a, reg_b, reg_c, program))
 }
 
 fn parse_registry(registry_line: &str) -> IResult<&str, u32> {
@@ -1,23 +1,19 @@
+use log::debug;
 use rayon::prelude::*;
 use std::collections::VecDeque;
 
 use crate::day17::model::Computer;
+use crate::error::AocError;
 
-pub fn solve_part_one(computer: &Computer) -> i128 {
+pub fn solve_part_one(computer: &Computer) -> Result<String, AocError> {
     let mut computer: Computer = computer.clone();
 
-    // Run the program
-    let stdout: Vec<u8> = computer.run();
-    let stdout_str: String = Computer::format_stdout(&stdout);
-
-    println!("Computer's output is:");
-    println!("{stdout_str}\n");
-
-    // Return dummy value
-    1234
+    // Run the program, the answer is the computer's output
+    let stdout: Vec<u8> = computer.run()?;
+    Ok(Computer::format_stdout(&stdout))
 }
 
-pub fn solve_part_two(computer: &Computer) -> i128 {
+pub fn solve_part_two(computer: &Computer) -> Result<Option<u32>, AocError> {
     let _reference_program: &Vec<u8> = &computer.stack;
     let _result: Vec<u8> = Vec::new();
 
@@ -40,17 +36,17 @@ pub fn solve_part_two(computer: &Computer) -> i128 {
 
     for i in 0..7 {
         let i = i << 15;
-        println!(
+        debug!(
             "{i:3>0} (={}) => {}",
             Computer::format_stdout(&decompose(i)),
-            Computer::format_stdout(&run_with_a(computer, i)),
+            Computer::format_stdout(&run_with_a(computer, i)?),
         );
     }
 
-    -1
+    Ok(None)
 }
 
-fn run_with_a(computer: &Computer, a: u32) -> Vec<u8> {
+fn run_with_a(computer: &Computer, a: u32) -> Result<Vec<u8>, AocError> {
     computer.with_a(a).run()
 }
 
@@ -3,42 +3,49 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Param, Params, DEFAULT_EXAMPLE};
 use crate::day18::model::Tile18;
 use crate::day18::parse::parse_input;
 use crate::day18::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Grid};
 
-const AXIS_LENGTH: usize = 71;
-const TEST_AXIS_LENGTH: usize = 7;
-
 #[derive(Default)]
 pub struct Day18 {
     falling_bytes_coordinates: Vec<Coordinates>,
 }
 
+register_solution!(Day18);
+
 impl Aoc24Solution for Day18 {
     fn get_day_number(&self) -> usize {
         18
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        let length: usize = if is_test {
-            TEST_AXIS_LENGTH
-        } else {
-            AXIS_LENGTH
-        };
-        self.falling_bytes_coordinates = parse_input(&self.get_data(is_test));
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("22"), Some("6,1"))]
+    }
+
+    fn get_params(&self) -> Vec<Param> {
+        vec![
+            Param::new("size", 71, 7).at_least(1), // Length of the memory space's sides
+            Param::new("bytes", 1024, 12),         // Number of bytes fallen before part one
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.falling_bytes_coordinates = parse_input(input)?;
+        Ok(())
+    }
 
-        solve_part_one(&self.falling_bytes_coordinates, length)
+    fn solve_part_one(&self, params: &Params) -> Result<Answer, AocError> {
+        let (size, bytes): (usize, usize) = (params.get("size")?, params.get("bytes")?);
+        Ok(solve_part_one(&self.falling_bytes_coordinates, size, bytes)?.into())
     }
 
-    fn solve_part_two(&mut self, is_test: bool) -> i128 {
-        let length: usize = if is_test {
-            TEST_AXIS_LENGTH
-        } else {
-            AXIS_LENGTH
-        };
-        solve_part_two(&self.falling_bytes_coordinates, length)
+    fn solve_part_two(&self, params: &Params) -> Result<Answer, AocError> {
+        let (size, bytes): (usize, usize) = (params.get("size")?, params.get("bytes")?);
+        Ok(solve_part_two(&self.falling_bytes_coordinates, size, bytes)?.into())
     }
 }
@@ -1,17 +1,24 @@
-use std::fmt::{Debug, Formatter};
+use crate::toolbox::render::{RenderTile, Rgb};
 
-#[derive(Clone, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum Tile18 {
     #[default]
     Empty,
     Corrupted,
 }
 
-impl Debug for Tile18 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile18 {
+    fn glyph(&self) -> char {
         match self {
-            Tile18::Empty => write!(f, "."),
-            Tile18::Corrupted => write!(f, "#"),
+            Tile18::Empty => '.',
+            Tile18::Corrupted => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile18::Empty => Rgb::WHITE,
+            Tile18::Corrupted => Rgb::DARK_GREY,
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
 use crate::toolbox::Coordinates;
 use nom::bytes::complete::tag;
 use nom::character::complete::i32 as i32_parser;
@@ -6,13 +8,11 @@ use nom::sequence::separated_pair;
 use nom::IResult;
 use std::io::ErrorKind;
 
-pub fn parse_input(input: &str) -> Vec<Coordinates> {
+pub fn parse_input(input: &str) -> Result<Vec<Coordinates>, AocError> {
     input
         .lines()
-        .map(|line| {
-            let (_, coord) = parse_coordinates(line).unwrap();
-            coord
-        })
+        .enumerate()
+        .map(|(index, line)| parse_line_with(index, line, parse_coordinates))
         .collect()
 }
 
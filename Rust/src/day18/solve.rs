@@ -1,18 +1,18 @@
 use crate::day18::dijkstra::custom_dijkstra;
 use crate::day18::model::Tile18;
-use crate::toolbox::{Coordinates, Grid};
+use crate::error::AocError;
+use crate::record_frame;
+use crate::toolbox::{Coordinates, Grid, Scene};
 
-const PART_ONE_LIMIT: usize = 1024;
-#[allow(dead_code)]
-const PART_ONE_TEST_LIMIT: usize = 12;
-
-const USED_LIMIT: usize = PART_ONE_LIMIT;
-
-pub fn solve_part_one(falling_bytes_coordinates: &[Coordinates], axis_length: usize) -> i128 {
+pub fn solve_part_one(
+    falling_bytes_coordinates: &[Coordinates],
+    axis_length: usize,
+    fallen_bytes: usize,
+) -> Result<i128, AocError> {
     let mut grid = Grid::fill(Tile18::Empty, axis_length, axis_length);
 
     // Make the bytes fall
-    for coord in falling_bytes_coordinates[0..USED_LIMIT].iter() {
+    for coord in first_bytes(falling_bytes_coordinates, fallen_bytes)?.iter() {
         grid.replace(coord, Tile18::Corrupted);
     }
 
@@ -24,14 +24,18 @@ pub fn solve_part_one(falling_bytes_coordinates: &[Coordinates], axis_length: us
 
     let (distance, _path) = custom_dijkstra(&grid, &start, &finish);
 
-    distance as i128
+    Ok(distance as i128)
 }
 
-pub fn solve_part_two(falling_bytes_coordinates: &[Coordinates], axis_length: usize) -> i128 {
+pub fn solve_part_two(
+    falling_bytes_coordinates: &[Coordinates],
+    axis_length: usize,
+    fallen_bytes: usize,
+) -> Result<String, AocError> {
     let mut grid = Grid::fill(Tile18::Empty, axis_length, axis_length);
 
     // Make the bytes fall
-    for coord in falling_bytes_coordinates[0..USED_LIMIT].iter() {
+    for coord in first_bytes(falling_bytes_coordinates, fallen_bytes)?.iter() {
         grid.replace(coord, Tile18::Corrupted);
     }
 
@@ -42,22 +46,37 @@ pub fn solve_part_two(falling_bytes_coordinates: &[Coordinates], axis_length: us
     };
 
     let (mut distance, mut path) = custom_dijkstra(&grid, &start, &finish);
-    let mut next_byte_index: usize = USED_LIMIT;
+    let mut next_byte_index: usize = fallen_bytes;
 
     while distance != i64::MAX {
-        let new_byte_coord: &Coordinates = &falling_bytes_coordinates[next_byte_index];
+        let new_byte_coord: &Coordinates = falling_bytes_coordinates
+            .get(next_byte_index)
+            .ok_or(AocError::invalid_state("No byte blocks the exit"))?;
         grid.replace(new_byte_coord, Tile18::Corrupted);
         next_byte_index += 1;
 
         if path.contains(new_byte_coord) {
             (distance, path) = custom_dijkstra(&grid, &start, &finish);
         }
+
+        record_frame!(Scene::new(&grid)
+            .path(&path, 'O')
+            .mark(*new_byte_coord, '#'));
     }
 
+    // This byte fell and blocked the last path
     let final_byte = &falling_bytes_coordinates[next_byte_index - 1];
-    println!("This byte fell and blocked the last path:");
-    println!("{},{}\n", final_byte.x, final_byte.y);
+    Ok(format!("{},{}", final_byte.x, final_byte.y))
+}
 
-    // Dummy value
-    1234
+fn first_bytes(
+    falling_bytes_coordinates: &[Coordinates],
+    count: usize,
+) -> Result<&[Coordinates], AocError> {
+    falling_bytes_coordinates
+        .get(0..count)
+        .ok_or(AocError::invalid_state(format!(
+            "Expected at least {count} falling bytes, got {}",
+            falling_bytes_coordinates.len()
+        )))
 }
@@ -5,50 +5,71 @@ use std::collections::{HashMap, HashSet};
 
 use rayon::prelude::*;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day19::parse::parse_input;
 use crate::day19::solve::count_possibilities;
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day19 {
     towels: HashSet<String>,
     patterns: Vec<String>,
 
-    // Special case where both results are computed at the same time
-    part_one_result: i128,
-    part_two_result: i128,
+    // Both answers come from the same count, run once while parsing
+    arrangements: Vec<i64>,
 }
 
+register_solution!(Day19);
+
 impl Aoc24Solution for Day19 {
     fn get_day_number(&self) -> usize {
         19
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.solve(is_test);
-        self.part_one_result
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("6"), Some("16"))]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.towels, self.patterns) = parse_input(input);
+        self.arrangements = self.count_arrangements()?;
+        Ok(())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        self.part_two_result
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        let possible: usize = self.arrangements.iter().filter(|x| **x != 0).count();
+        Ok(possible.into())
+    }
+
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        let arrangements: i64 = self.arrangements.iter().sum();
+        Ok(arrangements.into())
     }
 }
 
 impl Day19 {
-    fn solve(&mut self, is_test: bool) {
-        (self.towels, self.patterns) = parse_input(&self.get_data(is_test));
-
-        let min_len: usize = self.towels.iter().map(|tow| tow.len()).min().unwrap();
-        let max_len: usize = self.towels.iter().map(|tow| tow.len()).max().unwrap();
+    /// Number of ways to arrange towels into each pattern.
+    fn count_arrangements(&self) -> Result<Vec<i64>, AocError> {
+        let no_towel = || AocError::invalid_state("No towel available");
+        let min_len: usize = self
+            .towels
+            .iter()
+            .map(|tow| tow.len())
+            .min()
+            .ok_or_else(no_towel)?;
+        let max_len: usize = self
+            .towels
+            .iter()
+            .map(|tow| tow.len())
+            .max()
+            .ok_or_else(no_towel)?;
 
         let cache: HashMap<&str, i64> = HashMap::new();
-        let counts: Vec<i64> = self
+        Ok(self
             .patterns
             .par_iter()
             .map(|pat| count_possibilities(&self.towels, pat, &mut cache.clone(), min_len, max_len))
-            .collect();
-
-        self.part_one_result = counts.iter().filter(|x| **x != 0).count() as i128;
-        self.part_two_result = counts.iter().sum::<i64>() as i128;
+            .collect())
     }
 }
@@ -4,10 +4,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Param, Params, DEFAULT_EXAMPLE};
 use crate::day20::model::Tile20;
 use crate::day20::parse::parse_input;
 use crate::day20::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 use crate::toolbox::{Coordinates, Grid};
 
 #[derive(Default)]
@@ -17,17 +19,37 @@ pub struct Day20 {
     end: Coordinates,
 }
 
+register_solution!(Day20);
+
 impl Aoc24Solution for Day20 {
     fn get_day_number(&self) -> usize {
         20
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.racetrack, self.start, self.end) = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.racetrack, &self.start, &self.end) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("5"), Some("285"))]
+    }
+
+    fn get_params(&self) -> Vec<Param> {
+        // Minimum number of picoseconds saved by the counted cheats
+        vec![
+            Param::new("min_save_p1", 100, 20),
+            Param::new("min_save_p2", 100, 50),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.racetrack, self.start, self.end) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, params: &Params) -> Result<Answer, AocError> {
+        let minimum_time_save: i64 = params.get("min_save_p1")?;
+        Ok(solve_part_one(&self.racetrack, &self.start, &self.end, minimum_time_save).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.racetrack, &self.start, &self.end) as i128
+    fn solve_part_two(&self, params: &Params) -> Result<Answer, AocError> {
+        let minimum_time_save: i64 = params.get("min_save_p2")?;
+        Ok(solve_part_two(&self.racetrack, &self.start, &self.end, minimum_time_save).into())
     }
 }
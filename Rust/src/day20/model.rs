@@ -1,17 +1,24 @@
-use std::fmt::{Debug, Formatter};
+use crate::toolbox::render::{RenderTile, Rgb};
 
-#[derive(Clone, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum Tile20 {
     #[default]
     Empty,
     Wall,
 }
 
-impl Debug for Tile20 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl RenderTile for Tile20 {
+    fn glyph(&self) -> char {
         match self {
-            Tile20::Empty => write!(f, "."),
-            Tile20::Wall => write!(f, "#"),
+            Tile20::Empty => '.',
+            Tile20::Wall => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile20::Empty => Rgb::WHITE,
+            Tile20::Wall => Rgb::DARK_GREY,
         }
     }
 }
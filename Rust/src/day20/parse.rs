@@ -1,43 +1,21 @@
 use crate::day20::model::Tile20;
-use crate::toolbox::{Coordinates, Grid};
-use std::arch::x86_64::CpuidResult;
+use crate::error::AocError;
+use crate::toolbox::{Coordinates, FromGridChar, Grid, Markers};
 
-const CAPACITY: usize = 141;
+const START: char = 'S';
+const END: char = 'E';
 
-pub fn parse_input(input: &str) -> (Grid<Tile20>, Coordinates, Coordinates) {
-    let mut data: Vec<Vec<Tile20>> = Vec::with_capacity(CAPACITY);
-    let mut start_position: Option<Coordinates> = None;
-    let mut end_position: Option<Coordinates> = None;
-
-    for line in input.lines() {
-        let mut sub_data: Vec<Tile20> = Vec::new();
-        for chr in line.chars() {
-            match chr {
-                '.' => sub_data.push(Tile20::Empty),
-                '#' => sub_data.push(Tile20::Wall),
-                'S' => {
-                    start_position = Some(Coordinates {
-                        x: data.len() as i32,
-                        y: sub_data.len() as i32,
-                    });
-                    sub_data.push(Tile20::Empty);
-                }
-                'E' => {
-                    end_position = Some(Coordinates {
-                        x: data.len() as i32,
-                        y: sub_data.len() as i32,
-                    });
-                    sub_data.push(Tile20::Empty);
-                }
-                _ => unreachable!(),
-            }
+impl FromGridChar for Tile20 {
+    fn from_grid_char(chr: char) -> Option<Self> {
+        match chr {
+            '.' | START | END => Some(Tile20::Empty),
+            '#' => Some(Tile20::Wall),
+            _ => None,
         }
-        data.push(sub_data)
     }
+}
 
-    (
-        Grid::new(data),
-        start_position.expect("No start"),
-        end_position.expect("No end"),
-    )
+pub fn parse_input(input: &str) -> Result<(Grid<Tile20>, Coordinates, Coordinates), AocError> {
+    let (grid, markers): (Grid<Tile20>, Markers) = Grid::parse(input, &[START, END])?;
+    Ok((grid, markers.single(START)?, markers.single(END)?))
 }
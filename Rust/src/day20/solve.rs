@@ -5,21 +5,28 @@ use crate::toolbox::{Coordinates, Grid};
 use std::collections::HashMap;
 
 const PART_ONE_CHEAT_LENGTH: i64 = 2;
-const PART_ONE_MINIMUM_TIME_SAVE: i64 = 100;
-
 const PART_TWO_CHEAT_LENGTH: i64 = 20;
-const PART_TWO_MINIMUM_TIME_SAVE: i64 = 100;
 
-pub fn solve_part_one(track: &Grid<Tile20>, start: &Coordinates, end: &Coordinates) -> u64 {
+pub fn solve_part_one(
+    track: &Grid<Tile20>,
+    start: &Coordinates,
+    end: &Coordinates,
+    minimum_time_save: i64,
+) -> u64 {
     let (_len, path, distances): (i64, Vec<Coordinates>, HashMap<Coordinates, i64>) =
         custom_dijkstra(track, start, end);
     let reachable = compute_reachable(&path, PART_ONE_CHEAT_LENGTH);
-    count_shortcuts_above(&reachable, &distances, PART_ONE_MINIMUM_TIME_SAVE)
+    count_shortcuts_above(&reachable, &distances, minimum_time_save)
 }
 
-pub fn solve_part_two(track: &Grid<Tile20>, start: &Coordinates, end: &Coordinates) -> u64 {
+pub fn solve_part_two(
+    track: &Grid<Tile20>,
+    start: &Coordinates,
+    end: &Coordinates,
+    minimum_time_save: i64,
+) -> u64 {
     let (_len, path, distances): (i64, Vec<Coordinates>, HashMap<Coordinates, i64>) =
         custom_dijkstra(track, start, end);
     let reachable = compute_reachable(&path, PART_TWO_CHEAT_LENGTH);
-    count_shortcuts_above(&reachable, &distances, PART_TWO_MINIMUM_TIME_SAVE)
+    count_shortcuts_above(&reachable, &distances, minimum_time_save)
 }
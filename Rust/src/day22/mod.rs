@@ -2,26 +2,41 @@ pub mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day22::parse::parse_input;
 use crate::day22::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day22 {
     seeds: Vec<u64>,
 }
 
+register_solution!(Day22);
+
 impl Aoc24Solution for Day22 {
     fn get_day_number(&self) -> usize {
         22
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.seeds = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.seeds) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            Example::new(DEFAULT_EXAMPLE, Some("37327623"), None),
+            Example::new("part-two", None, Some("23")),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.seeds = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.seeds).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.seeds) as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.seeds).into())
     }
 }
@@ -1,3 +1,12 @@
-pub fn parse_input(input: &str) -> Vec<u64> {
-    input.lines().map(|val| val.parse().unwrap()).collect()
+use crate::error::AocError;
+
+pub fn parse_input(input: &str) -> Result<Vec<u64>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, val)| {
+            val.parse()
+                .map_err(|_| AocError::parse(index + 1, 1, format!("Invalid seed '{val}'")))
+        })
+        .collect()
 }
@@ -1,6 +1,7 @@
 use std::cmp;
 
 use itertools::{all, Itertools};
+use log::debug;
 use rayon::prelude::*;
 
 use crate::day22::model::{Buyer, Monkey};
@@ -51,7 +52,7 @@ pub fn solve_part_two(seeds: &[u64]) -> u64 {
 
     // Generate all possible buying instructions
     let all_buying_instructions: Vec<Vec<i64>> = get_buying_instructions_rec(vec![], 4);
-    println!(
+    debug!(
         "Total buying instructions: {}",
         all_buying_instructions.len()
     );
@@ -3,27 +3,42 @@ mod solve;
 
 use hashbrown::HashMap;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day23::parse::parse_input;
 use crate::day23::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day23 {
     graph: HashMap<String, Vec<String>>,
 }
 
+register_solution!(Day23);
+
 impl Aoc24Solution for Day23 {
     fn get_day_number(&self) -> usize {
         23
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.graph = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.graph) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(
+            DEFAULT_EXAMPLE,
+            Some("7"),
+            Some("co,de,ka,ta"),
+        )]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.graph = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.graph).into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.graph);
-        123 // Dummy value
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.graph).into())
     }
 }
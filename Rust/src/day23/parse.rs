@@ -1,18 +1,21 @@
 use hashbrown::HashMap;
 
+use crate::error::AocError;
+
 pub const CAPACITY: usize = 3400;
 
-pub fn parse_input(input: &str) -> HashMap<String, Vec<String>> {
+pub fn parse_input(input: &str) -> Result<HashMap<String, Vec<String>>, AocError> {
     let mut graph: HashMap<String, Vec<String>> = HashMap::with_capacity(CAPACITY);
 
-    for line in input.lines() {
-        let nodes: Vec<&str> = line.split("-").collect();
-        let left: String = nodes[0].to_string();
-        let right: String = nodes[1].to_string();
+    for (index, line) in input.lines().enumerate() {
+        let (left, right): (String, String) = match line.split_once("-") {
+            Some((left, right)) => (left.to_string(), right.to_string()),
+            None => return Err(AocError::parse(index + 1, 1, "Expected a 'a-b' connection")),
+        };
 
         graph.entry(left.clone()).or_default().push(right.clone());
         graph.entry(right).or_default().push(left);
     }
 
-    graph
+    Ok(graph)
 }
@@ -28,7 +28,7 @@ pub fn solve_part_one(graph: &HashMap<String, Vec<String>>) -> usize {
         .count()
 }
 
-pub fn solve_part_two(graph: &HashMap<String, Vec<String>>) {
+pub fn solve_part_two(graph: &HashMap<String, Vec<String>>) -> String {
     let mut hashset_graph: HashMap<String, HashSet<String>> = HashMap::new();
 
     for (key, value) in graph {
@@ -36,10 +36,8 @@ pub fn solve_part_two(graph: &HashMap<String, Vec<String>>) {
     }
 
     let biggest_clique: HashSet<String> = bron_kerbosch_alg(&hashset_graph);
-    let password: String = biggest_clique.iter().sorted().join(",");
-
-    println!("LAN password:");
-    println!("{password}");
+    // LAN password
+    biggest_clique.iter().sorted().join(",")
 }
 
 pub fn bron_kerbosch_alg(graph: &HashMap<String, HashSet<String>>) -> HashSet<String> {
@@ -2,27 +2,43 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day24::model::CableCircuit;
 use crate::day24::parse::parse_input;
 use crate::day24::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day24 {
     cable_circuit: CableCircuit,
 }
 
+register_solution!(Day24);
+
 impl Aoc24Solution for Day24 {
     fn get_day_number(&self) -> usize {
         24
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        self.cable_circuit = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.cable_circuit) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![
+            // Part two only applies to the real circuit
+            Example::new(DEFAULT_EXAMPLE, Some("2024"), None),
+            Example::new("small", Some("4"), None),
+        ]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.cable_circuit = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.cable_circuit)?.into())
     }
 
-    fn solve_part_two(&mut self, _is_test: bool) -> i128 {
-        solve_part_two(&self.cable_circuit) as i128
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.cable_circuit)?.into())
     }
 }
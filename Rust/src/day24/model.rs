@@ -3,6 +3,8 @@ use itertools::{izip, Itertools};
 use std::collections::HashSet;
 use std::fmt::{write, Debug, Formatter};
 
+use crate::error::AocError;
+
 #[derive(Clone, Eq, PartialEq)]
 pub enum LogicGate {
     And(String, String, String),
@@ -79,7 +81,7 @@ impl CableCircuit {
         }
     }
 
-    pub fn simulate(&mut self) {
+    pub fn simulate(&mut self) -> Result<(), AocError> {
         loop {
             let mut continue_loop: bool = false;
 
@@ -90,8 +92,8 @@ impl CableCircuit {
                     && self.cable_values.contains_key(left)
                     && self.cable_values.contains_key(right)
                 {
-                    let left_val: &bool = self.cable_values.get(left).unwrap();
-                    let right_val: &bool = self.cable_values.get(right).unwrap();
+                    let left_val: bool = self.cable_value(left)?;
+                    let right_val: bool = self.cable_value(right)?;
 
                     self.cable_values.insert(
                         String::from(dest),
@@ -106,14 +108,15 @@ impl CableCircuit {
             }
 
             if !continue_loop {
-                break;
+                return Ok(());
             }
         }
     }
 
     // Part two
 
-    pub fn inspect_shape(&self) {
+    /// Return the sorted, comma-separated, list of swapped wires.
+    pub fn inspect_shape(&self) -> Result<String, AocError> {
         let x_nodes: Vec<String> = self.generate_cables_starting_with("x");
         let y_nodes: Vec<String> = self.generate_cables_starting_with("y");
         let z_nodes: Vec<String> = self.generate_cables_starting_with("z");
@@ -126,12 +129,16 @@ impl CableCircuit {
 
             let x_and_y_gate = self
                 .get_gate_from_inputs(Some(&x_node), Some(&y_node), "AND")
-                .expect("Base AND gate missing");
+                .ok_or_else(|| {
+                    AocError::invalid_state(format!("No AND gate from {x_node} and {y_node}"))
+                })?;
             let x_and_y_node = x_and_y_gate.dest();
 
             let x_xor_y_gate = self
                 .get_gate_from_inputs(Some(&x_node), Some(&y_node), "XOR")
-                .expect("BASE XOR gate missing");
+                .ok_or_else(|| {
+                    AocError::invalid_state(format!("No XOR gate from {x_node} and {y_node}"))
+                })?;
             let x_xor_y_node = x_xor_y_gate.dest();
 
             //  1. Ensure X&Y goes to a OR
@@ -206,7 +213,7 @@ impl CableCircuit {
 
             // Attempt to build the carry for the next step
 
-            let gate_to_z_from_z = self.get_gate_from_dest(&z_node);
+            let gate_to_z_from_z = self.get_gate_from_dest(&z_node)?;
 
             let input_carry_node = match carry_cable {
                 Some(_) => carry_cable,
@@ -226,8 +233,7 @@ impl CableCircuit {
             };
         }
 
-        println!("Swapped wires ({}):", errored_cables.len());
-        println!("{}", errored_cables.iter().sorted().join(","));
+        Ok(errored_cables.iter().sorted().join(","))
     }
 
     fn get_gate_from_input<'a>(
@@ -278,13 +284,11 @@ impl CableCircuit {
         None
     }
 
-    fn get_gate_from_dest(&self, dest: &str) -> &LogicGate {
-        for gate in self.logic_gates.iter() {
-            if gate.dest() == dest {
-                return gate;
-            }
-        }
-        panic!("Not found")
+    fn get_gate_from_dest(&self, dest: &str) -> Result<&LogicGate, AocError> {
+        self.logic_gates
+            .iter()
+            .find(|gate| gate.dest() == dest)
+            .ok_or_else(|| AocError::invalid_state(format!("No gate outputs to {dest}")))
     }
 
     // Utils
@@ -295,27 +299,29 @@ impl CableCircuit {
             .keys()
             .filter(|key| key.starts_with(value))
             .count();
-        let count_log10: u32 = cable_count.ilog10();
 
+        // Cable numbers always have two digits, even with less than ten cables
         (0..cable_count)
-            .map(|i| match count_log10 {
-                0 => format!("{value}{i}"),
-                1 => format!("{value}{i:0>2}"),
-                2 => format!("{value}{i:0>3}"),
-                _ => unimplemented!("Too many values"),
-            })
+            .map(|i| format!("{value}{i:0>2}"))
             .collect()
     }
 
-    pub fn get_output(&self) -> i64 {
+    pub fn get_output(&self) -> Result<i64, AocError> {
         let mut result: i64 = 0;
 
         for (i, z_cable) in self.generate_cables_starting_with("z").iter().enumerate() {
-            if *self.cable_values.get(z_cable).unwrap() {
+            if self.cable_value(z_cable)? {
                 result += 1 << i;
             }
         }
 
-        result
+        Ok(result)
+    }
+
+    fn cable_value(&self, cable: &str) -> Result<bool, AocError> {
+        self.cable_values
+            .get(cable)
+            .copied()
+            .ok_or_else(|| AocError::invalid_state(format!("Cable {cable} has no value")))
     }
 }
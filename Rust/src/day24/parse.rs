@@ -9,43 +9,60 @@ use nom::sequence::tuple;
 use nom::IResult;
 
 use crate::day24::model::{CableCircuit, LogicGate};
+use crate::error::AocError;
+use crate::toolbox::parsing::parse_line_with;
 
-pub fn parse_input(input: &str) -> CableCircuit {
-    let input_parts: Vec<&str> = input.split("\r\n\r\n").collect(); // Windows windows windows ...
+pub fn parse_input(input: &str) -> Result<CableCircuit, AocError> {
+    let input_parts: Vec<&str> = input.split("\n\n").collect();
+    if input_parts.len() != 2 {
+        return Err(AocError::parse(
+            1,
+            1,
+            "Expected cable values and logic gates separated by an empty line",
+        ));
+    }
 
-    let initial_cable_values: HashMap<String, bool> = parse_cable_values(input_parts[0]);
-    let logic_gates: Vec<LogicGate> = parse_logic_gates(input_parts[1]);
+    let initial_cable_values: HashMap<String, bool> = parse_cable_values(input_parts[0])?;
+    // Gates start after the cable values and the empty line
+    let first_gate_line_index: usize = input_parts[0].lines().count() + 1;
+    let logic_gates: Vec<LogicGate> = parse_logic_gates(input_parts[1], first_gate_line_index)?;
 
-    CableCircuit::new(logic_gates, initial_cable_values)
+    Ok(CableCircuit::new(logic_gates, initial_cable_values))
 }
 
-fn parse_cable_values(input: &str) -> HashMap<String, bool> {
+fn parse_cable_values(input: &str) -> Result<HashMap<String, bool>, AocError> {
     let mut result: HashMap<String, bool> = HashMap::new();
 
-    for line in input.lines() {
-        let line_parts: Vec<&str> = line.split(": ").collect();
-        let cable_name: String = line_parts[0].to_string();
-        let cable_value: bool = match line_parts[1] {
+    for (line_index, line) in input.lines().enumerate() {
+        let (cable_name, cable_value) = line.split_once(": ").ok_or(AocError::parse(
+            line_index + 1,
+            1,
+            "Expected a 'name: value' cable",
+        ))?;
+        let cable_value: bool = match cable_value {
             "0" => false,
             "1" => true,
-            _ => unreachable!(),
+            _ => {
+                return Err(AocError::parse(
+                    line_index + 1,
+                    cable_name.len() + 3,
+                    format!("Invalid cable value '{cable_value}'"),
+                ))
+            }
         };
 
-        result.insert(cable_name, cable_value);
+        result.insert(cable_name.to_string(), cable_value);
     }
 
-    result
+    Ok(result)
 }
 
-fn parse_logic_gates(input: &str) -> Vec<LogicGate> {
-    let mut result: Vec<LogicGate> = Vec::new();
-
-    for line in input.lines() {
-        let (_, gate) = parse_gate(line).unwrap();
-        result.push(gate);
-    }
-
-    result
+fn parse_logic_gates(input: &str, first_line_index: usize) -> Result<Vec<LogicGate>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line_with(first_line_index + index, line, parse_gate))
+        .collect()
 }
 
 fn parse_gate(gate_line: &str) -> IResult<&str, LogicGate> {
@@ -1,13 +1,15 @@
 use hashbrown::{HashMap, HashSet};
+use log::debug;
 use petgraph::data::Build;
 use petgraph::dot::{Config, Dot};
 use petgraph::Graph;
 
 use crate::day24::model::CableCircuit;
+use crate::error::AocError;
 
-pub fn solve_part_one(cable_circuit: &CableCircuit) -> i64 {
+pub fn solve_part_one(cable_circuit: &CableCircuit) -> Result<i64, AocError> {
     let mut cable_circuit: CableCircuit = (*cable_circuit).clone();
-    cable_circuit.simulate();
+    cable_circuit.simulate()?;
     cable_circuit.get_output()
 }
 
@@ -37,18 +39,16 @@ pub fn solve_part_one(cable_circuit: &CableCircuit) -> i64 {
 ///    so it could be improved to detect errors found on other inputs (e.g. if found only 3 pairs of cable).
 ///
 /// [`Binary adder image`]: https://media.geeksforgeeks.org/wp-content/uploads/20240404130934/Binary-Adder-with-Logic-Gates.png
-pub fn solve_part_two(cable_circuit: &CableCircuit) -> i64 {
+pub fn solve_part_two(cable_circuit: &CableCircuit) -> Result<String, AocError> {
     // Run the simulation to make every cable appear
     let mut cable_circuit: CableCircuit = (*cable_circuit).clone();
-    cable_circuit.simulate();
+    cable_circuit.simulate()?;
 
     // Plot the graph
     // print_graph_dot(&cable_circuit);
 
     // Solve
-    cable_circuit.inspect_shape();
-
-    -2
+    cable_circuit.inspect_shape()
 }
 
 #[allow(dead_code)]
@@ -75,5 +75,5 @@ fn print_graph_dot(cable_circuit: &CableCircuit) {
         graph.add_edge(right_node, operation_node, ());
         graph.add_edge(operation_node, dest_node, ());
     }
-    println!("{:?}", Dot::with_config(&graph, &[]));
+    debug!("{:?}", Dot::with_config(&graph, &[]));
 }
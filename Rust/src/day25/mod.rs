@@ -2,10 +2,12 @@ mod model;
 mod parse;
 mod solve;
 
-use crate::aoc::Aoc24Solution;
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
 use crate::day25::model::{Key, Lock};
 use crate::day25::parse::parse_input;
 use crate::day25::solve::solve_part_one;
+use crate::error::AocError;
+use crate::registry::register_solution;
 
 #[derive(Default)]
 pub struct Day25 {
@@ -13,13 +15,27 @@ pub struct Day25 {
     locks: Vec<Lock>,
 }
 
+register_solution!(Day25);
+
 impl Aoc24Solution for Day25 {
     fn get_day_number(&self) -> usize {
         25
     }
 
-    fn solve_part_one(&mut self, is_test: bool) -> i128 {
-        (self.keys, self.locks) = parse_input(&self.get_data(is_test));
-        solve_part_one(&self.keys, &self.locks) as i128
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, Some("3"), None)]
+    }
+
+    fn get_part_count(&self) -> usize {
+        1
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        (self.keys, self.locks) = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.keys, &self.locks).into())
     }
 }
@@ -1,11 +1,15 @@
 use crate::day25::model::{Key, Lock};
+use crate::error::AocError;
+use crate::toolbox::parsing::unexpected_char;
 
-pub fn parse_input(input: &str) -> (Vec<Key>, Vec<Lock>) {
+pub fn parse_input(input: &str) -> Result<(Vec<Key>, Vec<Lock>), AocError> {
     let mut keys: Vec<Key> = Vec::new();
     let mut locks: Vec<Lock> = Vec::new();
 
-    for input_part in input.split("\r\n\r\n") { // Windows windows windows ...
-        let (is_lock, key_or_lock) = parse_key_and_lock(input_part);
+    let mut first_line_index: usize = 0;
+    for input_part in input.split("\n\n") {
+        let (is_lock, key_or_lock) = parse_key_and_lock(input_part, first_line_index)?;
+        first_line_index += input_part.lines().count() + 1;
 
         if is_lock {
             locks.push(key_or_lock)
@@ -14,10 +18,10 @@ pub fn parse_input(input: &str) -> (Vec<Key>, Vec<Lock>) {
         }
     }
 
-    (keys, locks)
+    Ok((keys, locks))
 }
 
-fn parse_key_and_lock(lines: &str) -> (bool, [u8; 5]) {
+fn parse_key_and_lock(lines: &str, first_line_index: usize) -> Result<(bool, [u8; 5]), AocError> {
     let mut is_lock: Option<bool> = None;
     let mut element: [u8; 5] = [0; 5];
 
@@ -33,11 +37,20 @@ fn parse_key_and_lock(lines: &str) -> (bool, [u8; 5]) {
         }
 
         for (i, c) in line.chars().enumerate() {
-            if c == '#' {
-                element[i] += 1
+            match (c, element.get_mut(i)) {
+                ('#', Some(height)) => *height += 1,
+                ('.', Some(_)) => {}
+                _ => return Err(unexpected_char(first_line_index + line_index, i, c)),
             }
         }
     }
 
-    (is_lock.unwrap(), element)
+    match is_lock {
+        Some(is_lock) => Ok((is_lock, element)),
+        None => Err(AocError::parse(
+            first_line_index + 1,
+            1,
+            "Expected a key or a lock",
+        )),
+    }
 }
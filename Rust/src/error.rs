@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+/// Error raised while loading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    MissingInput {
+        path: String,
+        source: io::Error,
+    },
+    /// Line and column are 1-based, as shown by text editors.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    InvalidState(String),
+    InvalidParam(String),
+    InvalidAnswers {
+        path: String,
+        message: String,
+    },
+    Scaffold {
+        path: String,
+        message: String,
+    },
+    Download {
+        url: String,
+        message: String,
+    },
+    Watch {
+        path: String,
+        message: String,
+    },
+    Subprocess {
+        command: String,
+        message: String,
+    },
+    Render {
+        path: String,
+        message: String,
+    },
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        AocError::InvalidState(message.into())
+    }
+
+    pub fn invalid_param(message: impl Into<String>) -> Self {
+        AocError::InvalidParam(message.into())
+    }
+
+    pub fn invalid_answers(path: &Path, message: impl Into<String>) -> Self {
+        AocError::InvalidAnswers {
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn download(url: &str, message: impl Into<String>) -> Self {
+        AocError::Download {
+            url: url.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn scaffold(path: &Path, message: impl Into<String>) -> Self {
+        AocError::Scaffold {
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn watch(path: &Path, message: impl Into<String>) -> Self {
+        AocError::Watch {
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn subprocess(command: &str, message: impl Into<String>) -> Self {
+        AocError::Subprocess {
+            command: command.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn render(path: &Path, message: impl Into<String>) -> Self {
+        AocError::Render {
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingInput { path, source } => {
+                write!(f, "Missing input '{path}' ({source})")
+            }
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "Parse error at {line}:{column}: {message}"),
+            AocError::InvalidState(message) => write!(f, "Invalid puzzle state: {message}"),
+            AocError::InvalidParam(message) => write!(f, "Invalid puzzle parameter: {message}"),
+            AocError::InvalidAnswers { path, message } => {
+                write!(f, "Invalid answers file '{path}': {message}")
+            }
+            AocError::Scaffold { path, message } => {
+                write!(f, "Cannot scaffold '{path}': {message}")
+            }
+            AocError::Download { url, message } => {
+                write!(f, "Cannot download '{url}': {message}")
+            }
+            AocError::Watch { path, message } => write!(f, "Cannot watch '{path}': {message}"),
+            AocError::Subprocess { command, message } => {
+                write!(f, "Command '{command}' failed: {message}")
+            }
+            AocError::Render { path, message } => write!(f, "Cannot render '{path}': {message}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::MissingInput { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,18 @@
 #![allow(unused_imports)]
 
+pub mod answers;
 pub mod aoc;
+pub mod bench;
 pub mod cli;
+pub mod error;
+pub mod logging;
+pub mod parity;
+pub mod provider;
+pub mod recorder;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod watch;
 
 pub mod toolbox;
 
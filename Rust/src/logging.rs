@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Diagnostics of the solutions, written to stderr so that answers stay alone on stdout.
+///
+/// Each day logs under its own target, e.g. `day22`, whose level may differ from the global one.
+pub struct Logger {
+    level: LevelFilter,
+    days: BTreeMap<usize, LevelFilter>,
+}
+
+impl Logger {
+    pub fn new(level: LevelFilter, days: BTreeMap<usize, LevelFilter>) -> Self {
+        Logger { level, days }
+    }
+
+    /// Install the logger for the whole process.
+    pub fn init(self) {
+        let max_level: LevelFilter = self
+            .days
+            .values()
+            .fold(self.level, |max, level| max.max(*level));
+        log::set_boxed_logger(Box::new(self)).expect("The logger is only installed once");
+        log::set_max_level(max_level);
+    }
+
+    fn level_of(&self, target: &str) -> LevelFilter {
+        target_day(target)
+            .and_then(|day| self.days.get(&day))
+            .copied()
+            .unwrap_or(self.level)
+    }
+}
+
+/// Day logging under a target, which is the module path by default, e.g. `aoc24::day22::solve`.
+pub fn target_day(target: &str) -> Option<usize> {
+    target
+        .split("::")
+        .find_map(|segment| segment.strip_prefix("day")?.parse().ok())
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_of(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let target: String = match target_day(record.target()) {
+            Some(day) => format!("day{day:0>2}"),
+            None => record.target().to_string(),
+        };
+        let prefix: String = format!("[{} {target}]", record.level());
+        let prefix = match record.level() {
+            Level::Error => prefix.red(),
+            Level::Warn => prefix.yellow(),
+            Level::Info => prefix.normal(),
+            Level::Debug | Level::Trace => prefix.dimmed(),
+        };
+        eprintln!("{prefix} {}", record.args());
+    }
+
+    fn flush(&self) {}
+}
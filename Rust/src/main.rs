@@ -1,148 +1,421 @@
-use std::process::exit;
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::{self, exit};
 
-use clap::Parser;
 use colored::Colorize;
-#[cfg(feature = "benchmark")]
-use instant::Instant;
-
-use aoc24::aoc::Aoc24Solution;
-use aoc24::cli::Args;
-
-use aoc24::day00::Day00;
-use aoc24::day01::Day01;
-use aoc24::day02::Day02;
-use aoc24::day03::Day03;
-use aoc24::day04::Day04;
-use aoc24::day05::Day05;
-use aoc24::day06::Day06;
-use aoc24::day07::Day07;
-use aoc24::day08::Day08;
-use aoc24::day09::Day09;
-use aoc24::day10::Day10;
-use aoc24::day11::Day11;
-use aoc24::day12::Day12;
-use aoc24::day13::Day13;
-use aoc24::day14::Day14;
-use aoc24::day15::Day15;
-use aoc24::day16::Day16;
-use aoc24::day17::Day17;
-use aoc24::day18::Day18;
-use aoc24::day19::Day19;
-use aoc24::day20::Day20;
-use aoc24::day22::Day22;
-use aoc24::day23::Day23;
-use aoc24::day24::Day24;
-use aoc24::day25::Day25;
+
+use aoc24::answers::{answers_path, AnswerStore};
+use aoc24::aoc::{resolve_data_dir, Aoc24Solution, InputSource};
+use aoc24::bench::{bench_day, print_records, BenchRecord};
+use aoc24::cli::{
+    parse_args, Args, BenchArgs, Command, DaySelection, ExampleSelection, FrameArgs, InputArgs,
+    NewArgs, OutputFormat, ParityArgs, PlayArgs, WatchArgs,
+};
+use aoc24::error::AocError;
+use aoc24::logging::Logger;
+use aoc24::parity::{check_parity, default_python, print_parity, ParityReport, PythonSolutions};
+use aoc24::recorder::{self, Frame, FrameRecorder};
+use aoc24::registry::{find_solution, select_solutions};
+use aoc24::runner::{print_day, print_reports, run_days, select_inputs, DayReport};
+use aoc24::scaffold::scaffold_day;
+use aoc24::watch::watch_day;
 
 fn main() {
-    println!("Advent of Code 2024!\n");
-    let args: Args = Args::parse();
-    let target_day: usize = args.day;
+    let args: Args = parse_args();
+    Logger::new(args.log.level(), args.log.days.iter().copied().collect()).init();
 
-    if target_day > 25 {
-        println!("Are you joking ?");
-        exit(1)
+    match &args.command {
+        Some(Command::Bench(bench_args)) => bench(bench_args),
+        Some(Command::New(new_args)) => new_day(new_args),
+        Some(Command::Watch(watch_args)) => watch(watch_args),
+        Some(Command::Parity(parity_args)) => parity(parity_args),
+        Some(Command::Play(play_args)) => play(play_args),
+        None => run(&args),
+    }
+}
+
+/// Solve the selected day(s), then print their answers.
+fn run(args: &Args) -> ! {
+    // Keep machine-readable outputs clean
+    if args.format == OutputFormat::Text {
+        println!("Advent of Code 2024!\n");
     }
+    let selection: &DaySelection = args.day.as_ref().expect("A day is required");
 
-    // Create index of solutions
-    let mut solutions: Vec<Box<dyn Aoc24Solution>> = vec![
-        Box::new(Day00::default()),
-        Box::new(Day01::default()),
-        Box::new(Day02::default()),
-        Box::new(Day03::default()),
-        Box::new(Day04::default()),
-        Box::new(Day05::default()),
-        Box::new(Day06::default()),
-        Box::new(Day07::default()),
-        Box::new(Day08::default()),
-        Box::new(Day09::default()),
-        Box::new(Day10::default()),
-        Box::new(Day11::default()),
-        Box::new(Day12::default()),
-        Box::new(Day13::default()),
-        Box::new(Day14::default()),
-        Box::new(Day15::default()),
-        Box::new(Day16::default()),
-        Box::new(Day17::default()),
-        Box::new(Day18::default()),
-        Box::new(Day19::default()),
-        Box::new(Day20::default()),
-        Box::new(Day22::default()),
-        Box::new(Day23::default()),
-        Box::new(Day24::default()),
-        Box::new(Day25::default()),
-    ];
-
-    // Find the right solution
-    let mut found: bool = false;
-
-    for sol in solutions.iter_mut() {
-        if sol.get_day_number() != args.day {
-            continue;
+    if let DaySelection::Single(day) = selection {
+        if *day > 25 {
+            println!("Are you joking ?");
+            exit(1)
         }
+    }
+    let source: InputSource = input_source(selection, &args.inputs);
 
-        #[cfg(feature = "benchmark")]
-        {
-            // Run part 1
-            let now = Instant::now();
-            let part_one: i128 = sol.solve_part_one(args.use_test);
-            let part_01_time = now.elapsed().as_micros();
+    let examples: Option<ExampleSelection> = args.inputs.examples();
 
-            println!(
-                "[Day {target_day:0>2}] Part 1: {} in {part_01_time} us",
-                part_one.to_string().cyan()
-            );
+    // Solutions register themselves, see `register_solution!`
+    let solutions: Vec<Box<dyn Aoc24Solution>> = select_solutions(selection);
+    if solutions.is_empty() {
+        no_solution(selection)
+    }
+
+    check_params(&solutions, &args.inputs.params);
+
+    if args.frames.dir.is_some() {
+        FrameRecorder::new(args.frames.limit).init();
+    }
+
+    let mut reports: Vec<DayReport> = run_days(
+        solutions,
+        &source,
+        examples.as_ref(),
+        &args.inputs.params,
+        args.part,
+        args.timeout,
+        args.jobs,
+    );
+    if reports.is_empty() {
+        no_example(examples.as_ref())
+    }
+
+    if args.check || args.record {
+        check_and_record(&mut reports, args);
+    }
+
+    match (selection, args.format) {
+        (DaySelection::Single(_), OutputFormat::Text) => reports.iter().for_each(print_day),
+        _ => print_reports(&reports, args.format),
+    }
+
+    if let Some(dir) = &args.frames.dir {
+        export_frames(Path::new(dir), &args.frames);
+    }
+
+    match reports.iter().any(|report| report.is_failure()) {
+        true => exit(1),
+        false => exit(0),
+    }
+}
+
+/// Time the selected day(s) over several runs, then print the statistics.
+fn bench(args: &BenchArgs) -> ! {
+    // Keep machine-readable outputs clean
+    if args.format == OutputFormat::Text {
+        println!("Advent of Code 2024!\n");
+    }
+
+    let source: InputSource = input_source(&args.day, &args.inputs);
+    let examples: Option<ExampleSelection> = args.inputs.examples();
+    let solutions: Vec<Box<dyn Aoc24Solution>> = select_solutions(&args.day);
+    if solutions.is_empty() {
+        no_solution(&args.day)
+    }
+
+    check_params(&solutions, &args.inputs.params);
+
+    let mut records: Vec<BenchRecord> = Vec::new();
+    let mut has_failed: bool = false;
 
-            // Run part 2
-            if part_one != -1 {
-                let now = Instant::now();
-                let part_two: i128 = sol.solve_part_two(args.use_test);
-                let part_02_time = now.elapsed().as_micros();
-
-                if args.day <= 25 {
-                    println!(
-                        "[Day {target_day:0>2}] Part 2: {} in {part_02_time} us",
-                        part_two.to_string().cyan()
-                    );
-                } else {
-                    println!("Merry Christmas !");
+    for mut sol in solutions {
+        for example in select_inputs(sol.as_ref(), examples.as_ref()) {
+            let overrides: &[(String, i64)] = &args.inputs.params;
+            match bench_day(
+                sol.as_mut(),
+                &source,
+                example,
+                overrides,
+                args.part,
+                args.runs,
+                args.warmup,
+            ) {
+                Ok(day_records) => records.extend(day_records),
+                Err(error) => {
+                    let day: usize = sol.get_day_number();
+                    eprintln!("{}", format!("[Day {day:0>2}] {error}").red());
+                    has_failed = true;
                 }
-            } else {
-                println!("No part two yet");
-            };
+            }
         }
+    }
+    if records.is_empty() && !has_failed {
+        no_example(examples.as_ref())
+    }
 
-        #[cfg(not(feature = "benchmark"))]
-        {
-            let part_one: i128 = sol.solve_part_one(args.use_test);
-            println!(
-                "[Day {target_day:0>2}] Part 1: {}",
-                part_one.to_string().cyan()
-            );
+    print_records(&records, args.format);
 
-            if part_one != -1 {
-                if args.day < 25 {
-                    let part_two: i128 = sol.solve_part_two(args.use_test);
-                    println!(
-                        "[Day {target_day:0>2}] Part 2: {}",
-                        part_two.to_string().cyan()
-                    );
-                } else {
-                    println!("Merry Christmas !");
-                }
-            } else {
-                println!("No part two yet");
+    match has_failed {
+        true => exit(1),
+        false => exit(0),
+    }
+}
+
+/// Resolve where inputs are read from, a given input file only making sense for a single day.
+fn input_source(selection: &DaySelection, inputs: &InputArgs) -> InputSource {
+    let source: InputSource = inputs.source();
+    if !matches!(source, InputSource::DataDir(_)) && !matches!(selection, DaySelection::Single(_)) {
+        eprintln!("{}", "--input requires a single day".red());
+        exit(1)
+    }
+    source
+}
+
+/// Exit if a parameter is overridden while none of the selected days declares it.
+fn check_params(solutions: &[Box<dyn Aoc24Solution>], overrides: &[(String, i64)]) {
+    let declared: Vec<&str> = solutions
+        .iter()
+        .flat_map(|sol| sol.get_params())
+        .map(|param| param.name)
+        .collect();
+
+    for (name, _) in overrides {
+        if !declared.contains(&name.as_str()) {
+            let known: String = match declared.is_empty() {
+                true => String::from("none"),
+                false => declared.join(", "),
             };
+            eprintln!(
+                "{}",
+                format!("Unknown parameter '{name}' (known: {known})").red()
+            );
+            exit(1)
         }
+    }
+}
 
-        found = true;
-        break;
+/// Generate a new day in this crate's sources.
+fn new_day(args: &NewArgs) -> ! {
+    let src_dir: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let data_dir: PathBuf = resolve_data_dir(args.data_dir.as_deref());
+
+    for path in or_exit(scaffold_day(args.day, &src_dir, &data_dir)) {
+        println!("Created {}", path.display());
     }
+    println!(
+        "Registered day{:0>2} in {}",
+        args.day,
+        src_dir.join("lib.rs").display()
+    );
+    exit(0)
+}
+
+/// Solve a day whenever its inputs change, rebuilding then restarting when its sources change.
+fn watch(args: &WatchArgs) -> ! {
+    println!("Advent of Code 2024!\n");
 
-    if !found {
-        println!("No solution for day {target_day:0>2}");
+    let selection: DaySelection = DaySelection::Single(args.day);
+    let source: InputSource = input_source(&selection, &args.inputs);
+    if source == InputSource::Stdin {
+        eprintln!("{}", "Cannot watch stdin, use an input file instead".red());
         exit(1)
     }
 
+    let solutions: Vec<Box<dyn Aoc24Solution>> = select_solutions(&selection);
+    if solutions.is_empty() {
+        no_solution(&selection)
+    }
+    check_params(&solutions, &args.inputs.params);
+
+    // Taken now, as the executable gets replaced by rebuilds
+    let exe: PathBuf = env::current_exe().expect("The executable has a path");
+    let manifest_dir: &Path = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let mut restart = || {
+        println!(
+            "\n{}",
+            format!("Sources of day {:0>2} changed, rebuilding", args.day).dimmed()
+        );
+        if rebuild(manifest_dir) {
+            restart(&exe)
+        }
+        println!("{}", "Build failed, fix the sources to try again".red());
+    };
+
+    or_exit(watch_day(args, &manifest_dir.join("src"), &mut restart));
     exit(0)
 }
+
+/// Build this crate with the profile of the running executable.
+fn rebuild(manifest_dir: &Path) -> bool {
+    let mut cargo = process::Command::new(env::var_os("CARGO").unwrap_or(OsString::from("cargo")));
+    cargo
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+
+    cargo.status().is_ok_and(|status| status.success())
+}
+
+/// Replace the current process by a new run of the executable, with the same arguments.
+fn restart(exe: &Path) -> ! {
+    let mut command = process::Command::new(exe);
+    command.args(env::args_os().skip(1));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let error = command.exec();
+        eprintln!(
+            "{}",
+            format!("Cannot restart {}: {error}", exe.display()).red()
+        );
+        exit(1)
+    }
+
+    #[cfg(not(unix))]
+    match command.status() {
+        Ok(status) => exit(status.code().unwrap_or(1)),
+        Err(error) => {
+            eprintln!(
+                "{}",
+                format!("Cannot restart {}: {error}", exe.display()).red()
+            );
+            exit(1)
+        }
+    }
+}
+
+/// Solve the selected day(s) in both Rust and Python, then compare their answers.
+fn parity(args: &ParityArgs) -> ! {
+    println!("Advent of Code 2024!\n");
+
+    let source: InputSource = input_source(&args.day, &args.inputs);
+    if source == InputSource::Stdin {
+        eprintln!(
+            "{}",
+            "Cannot compare on stdin, use an input file instead".red()
+        );
+        exit(1)
+    }
+
+    let python_dir: PathBuf = match &args.python_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../Python3"),
+    };
+    let python: PythonSolutions = PythonSolutions::new(
+        &args.python.clone().unwrap_or_else(default_python),
+        &python_dir,
+    );
+
+    // Only days solved in both languages can be compared
+    let solutions: Vec<Box<dyn Aoc24Solution>> = select_solutions(&args.day)
+        .into_iter()
+        .filter(|sol| python.has_day(sol.get_day_number()))
+        .collect();
+    if solutions.is_empty() {
+        match &args.day {
+            DaySelection::Single(day) => println!("No Python solution for day {day:0>2}"),
+            selection => println!("No Python solution for days {selection:?}"),
+        }
+        exit(1)
+    }
+    check_params(&solutions, &args.inputs.params);
+
+    let examples: Option<ExampleSelection> = args.inputs.examples();
+    let mut reports: Vec<ParityReport> = Vec::new();
+    for sol in solutions {
+        let day: usize = sol.get_day_number();
+        let inputs: Vec<Option<&str>> = select_inputs(sol.as_ref(), examples.as_ref());
+        for example in inputs {
+            let sol: Box<dyn Aoc24Solution> = find_solution(day).expect("The day has a solution");
+            reports.push(check_parity(
+                sol,
+                &python,
+                &source,
+                example,
+                &args.inputs.params,
+            ));
+        }
+    }
+    if reports.is_empty() {
+        no_example(examples.as_ref())
+    }
+
+    print_parity(&reports);
+
+    match reports.iter().any(|report| report.is_failure()) {
+        true => exit(1),
+        false => exit(0),
+    }
+}
+
+/// Show recorded frames in the terminal.
+fn play(args: &PlayArgs) -> ! {
+    or_exit(recorder::play(
+        Path::new(&args.file),
+        args.fps,
+        args.every,
+        args.step,
+    ));
+    exit(0)
+}
+
+fn no_solution(selection: &DaySelection) -> ! {
+    match selection {
+        DaySelection::Single(day) => println!("No solution for day {day:0>2}"),
+        _ => println!("No solution for days {selection:?}"),
+    }
+    exit(1)
+}
+
+fn no_example(examples: Option<&ExampleSelection>) -> ! {
+    match examples {
+        Some(ExampleSelection::Named(name)) => {
+            println!("No example named '{name}' for the selected day(s)")
+        }
+        _ => println!("No example for the selected day(s)"),
+    }
+    exit(1)
+}
+
+/// Compare the answers against the known ones and/or store them, depending on the flags.
+fn check_and_record(reports: &mut [DayReport], args: &Args) {
+    let path: PathBuf = answers_path(&args.inputs.data_dir());
+    let mut answers: AnswerStore = or_exit(AnswerStore::load(&path));
+
+    if args.check {
+        for report in reports.iter_mut() {
+            report.check(&answers);
+        }
+    }
+
+    if args.record {
+        let recorded: usize = reports
+            .iter()
+            .map(|report| report.record(&mut answers))
+            .sum();
+        or_exit(answers.save(&path));
+        if args.format == OutputFormat::Text {
+            println!("Recorded {recorded} answer(s) into {}\n", path.display());
+        }
+    }
+}
+
+/// Write the frames recorded by the solutions, which only the simulating days record.
+fn export_frames(dir: &Path, args: &FrameArgs) {
+    let sequences: BTreeMap<String, Vec<Frame>> = recorder::take_sequences();
+    if sequences.is_empty() {
+        eprintln!("{}", "No frame recorded by the selected day(s)".yellow());
+        return;
+    }
+
+    let created: Vec<PathBuf> = or_exit(recorder::export(&sequences, dir, args));
+    // Keep machine-readable outputs clean
+    for path in created {
+        eprintln!("Recorded frames into {}", path.display());
+    }
+}
+
+/// Unwrap a result, or report the error and exit.
+fn or_exit<T>(result: Result<T, AocError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error.to_string().red());
+            exit(1)
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use colored::Colorize;
+
+use crate::aoc::{Aoc24Solution, InputSource};
+use crate::cli::PartSelection;
+use crate::error::AocError;
+use crate::runner::{run_day, DayReport, ParseOutcome, PartOutcome};
+
+/// Interpreter running the Python solutions, unless `--python` is given.
+pub const PYTHON_ENV: &str = "AOC_PYTHON";
+const DEFAULT_PYTHON: &str = "python3";
+
+/// The `aoc24` package of the Python solutions, run as `python -m aoc24 <day> --input <file>`.
+pub struct PythonSolutions {
+    python: String,
+    project_dir: PathBuf, // Holding the package
+}
+
+impl PythonSolutions {
+    pub fn new(python: &str, project_dir: &Path) -> Self {
+        PythonSolutions {
+            python: python.to_string(),
+            project_dir: project_dir.to_path_buf(),
+        }
+    }
+
+    pub fn has_day(&self, day: usize) -> bool {
+        self.project_dir
+            .join("aoc24")
+            .join(format!("day{day:0>2}"))
+            .is_dir()
+    }
+
+    /// Answers of both parts on the given file, `None` for a part without answer.
+    ///
+    /// Parts are printed one after the other, so the first one may be answered before the second
+    /// one fails.
+    pub fn solve(&self, day: usize, input: &Path) -> [Result<Option<String>, AocError>; 2] {
+        let command: String = format!("{} -m aoc24 {day} --input {}", self.python, input.display());
+        let output: Output = match Command::new(&self.python)
+            .args(["-m", "aoc24", &day.to_string(), "--input"])
+            .arg(input)
+            .current_dir(&self.project_dir)
+            .output()
+        {
+            Ok(output) => output,
+            Err(error) => {
+                return [1, 2].map(|_| Err(AocError::subprocess(&command, error.to_string())))
+            }
+        };
+
+        // Solutions may print other things, answers are on lines like `Part 1: 11`
+        let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+        // The last line of a traceback tells what went wrong
+        let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+        let message: &str = stderr.lines().last().unwrap_or("No error output");
+
+        [1, 2].map(|part| {
+            let answer: Option<String> = stdout
+                .lines()
+                .rev()
+                .find_map(|line| line.strip_prefix(&format!("Part {part}:")))
+                .map(|answer| answer.trim().to_string());
+            match answer {
+                Some(answer) => Ok(Some(answer).filter(|answer| answer != "None")),
+                None if output.status.success() => Ok(None),
+                None => Err(AocError::subprocess(
+                    &command,
+                    format!("{} ({message})", output.status),
+                )),
+            }
+        })
+    }
+}
+
+/// Interpreter from `AOC_PYTHON`, or `python3`.
+pub fn default_python() -> String {
+    std::env::var(PYTHON_ENV).unwrap_or(String::from(DEFAULT_PYTHON))
+}
+
+/// Comparison of the answers of both implementations for a part.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PartParity {
+    Same(String),
+    Different { rust: String, python: String },
+    OnlyRust(String),
+    OnlyPython(String),
+    Unsolved,
+    Failed(String), // Error of either implementation
+}
+
+impl PartParity {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, PartParity::Different { .. } | PartParity::Failed(_))
+    }
+
+    fn compare(
+        rust: Result<Option<String>, String>,
+        python: Result<Option<String>, String>,
+    ) -> Self {
+        match (rust, python) {
+            (Err(error), _) => PartParity::Failed(format!("Rust: {error}")),
+            (_, Err(error)) => PartParity::Failed(format!("Python: {error}")),
+            (Ok(Some(rust)), Ok(Some(python))) if rust == python => PartParity::Same(rust),
+            (Ok(Some(rust)), Ok(Some(python))) => PartParity::Different { rust, python },
+            (Ok(Some(rust)), Ok(None)) => PartParity::OnlyRust(rust),
+            (Ok(None), Ok(Some(python))) => PartParity::OnlyPython(python),
+            (Ok(None), Ok(None)) => PartParity::Unsolved,
+        }
+    }
+}
+
+/// Comparison of both implementations on an input of a day.
+#[derive(Debug)]
+pub struct ParityReport {
+    pub day: usize,
+    pub input: PathBuf,
+    pub parts: [PartParity; 2],
+}
+
+impl ParityReport {
+    pub fn is_failure(&self) -> bool {
+        self.parts.iter().any(PartParity::is_failure)
+    }
+}
+
+/// Solve an input with both implementations, then compare their answers part by part.
+///
+/// The example is `None` for the real input, parameters overrides only apply to the Rust solution.
+pub fn check_parity(
+    solution: Box<dyn Aoc24Solution>,
+    python: &PythonSolutions,
+    source: &InputSource,
+    example: Option<&str>,
+    overrides: &[(String, i64)],
+) -> ParityReport {
+    let day: usize = solution.get_day_number();
+    let input: PathBuf = match source {
+        InputSource::DataDir(data_dir) => match example {
+            Some(name) => solution.build_test_path(data_dir, name),
+            None => solution.build_input_path(data_dir),
+        },
+        InputSource::File(path) => path.clone(),
+        InputSource::Stdin => unreachable!("Stdin cannot be read twice"),
+    };
+
+    // Solving in Rust first downloads the real input if needed
+    let report: DayReport = run_day(
+        solution,
+        source,
+        example,
+        overrides,
+        PartSelection::Both,
+        None,
+    );
+    let rust: [Result<Option<String>, String>; 2] =
+        [&report.part_one, &report.part_two].map(|outcome| match (&report.parse, outcome) {
+            (ParseOutcome::Failed(error), _) => Err(error.to_string()),
+            (ParseOutcome::Panicked(message), _) => Err(format!("panicked: {message}")),
+            (ParseOutcome::TimedOut(timeout), _) => {
+                Err(format!("parse timed out after {timeout:?}"))
+            }
+            (_, PartOutcome::Solved(answer, _)) => Ok(Some(answer.to_string())),
+            (_, PartOutcome::Unsolved | PartOutcome::NotImplemented | PartOutcome::Skipped) => {
+                Ok(None)
+            }
+            (_, PartOutcome::Failed(error)) => Err(error.to_string()),
+            (_, PartOutcome::Panicked(message)) => Err(format!("panicked: {message}")),
+            (_, PartOutcome::TimedOut(timeout)) => Err(format!("timed out after {timeout:?}")),
+        });
+
+    // The Python package runs from its own directory
+    let absolute: PathBuf = input.canonicalize().unwrap_or(input.clone());
+    let python: [Result<Option<String>, String>; 2] = python
+        .solve(day, &absolute)
+        .map(|answer| answer.map_err(|error| error.to_string()));
+
+    let [rust_one, rust_two] = rust;
+    let [python_one, python_two] = python;
+    ParityReport {
+        day,
+        input,
+        parts: [
+            PartParity::compare(rust_one, python_one),
+            PartParity::compare(rust_two, python_two),
+        ],
+    }
+}
+
+pub fn print_parity(reports: &[ParityReport]) {
+    for report in reports {
+        println!("[Day {:0>2}] {}", report.day, report.input.display());
+        for (part, parity) in [1, 2].into_iter().zip(&report.parts) {
+            let line: String = match parity {
+                PartParity::Same(answer) => format!("{} ({answer})", "same".green()),
+                PartParity::Different { rust, python } => {
+                    format!("{} (Rust {rust}, Python {python})", "different".red())
+                }
+                PartParity::OnlyRust(answer) => {
+                    format!("{} ({answer})", "only solved in Rust".yellow())
+                }
+                PartParity::OnlyPython(answer) => {
+                    format!("{} ({answer})", "only solved in Python".yellow())
+                }
+                PartParity::Unsolved => "unsolved by both".dimmed().to_string(),
+                PartParity::Failed(error) => format!("{} ({error})", "failed".red()),
+            };
+            println!("  Part {part}: {line}");
+        }
+    }
+
+    let mismatches: usize = reports
+        .iter()
+        .flat_map(|report| &report.parts)
+        .filter(|parity| parity.is_failure())
+        .count();
+    println!(
+        "\n{} input(s) compared, {mismatches} mismatch(es)",
+        reports.len()
+    );
+}
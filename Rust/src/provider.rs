@@ -0,0 +1,152 @@
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::AocError;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "aoc24 (github.com/Vincent-Bonino/AdventOfCode2024)";
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Source of the real puzzle inputs.
+pub trait InputProvider {
+    fn fetch(&self, day: usize) -> Result<String, AocError>;
+}
+
+/// Inputs stored as `inputs/dayNN.txt` in the data directory.
+pub struct FsCache {
+    dir: PathBuf,
+}
+
+impl FsCache {
+    pub fn new(data_dir: &Path) -> Self {
+        FsCache {
+            dir: data_dir.join("inputs"),
+        }
+    }
+
+    pub fn path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("day{day:0>2}.txt"))
+    }
+
+    pub fn contains(&self, day: usize) -> bool {
+        self.path(day).is_file()
+    }
+
+    pub fn store(&self, day: usize, input: &str) -> Result<(), AocError> {
+        let path: PathBuf = self.path(day);
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, input))
+            .map_err(|source| AocError::MissingInput {
+                path: path.display().to_string(),
+                source,
+            })
+    }
+}
+
+impl InputProvider for FsCache {
+    fn fetch(&self, day: usize) -> Result<String, AocError> {
+        let path: PathBuf = self.path(day);
+        fs::read_to_string(&path).map_err(|source| AocError::MissingInput {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+/// Inputs downloaded from the Advent of Code website, as the user owning the session token.
+pub struct HttpProvider {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpProvider {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        HttpProvider {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(HTTP_TIMEOUT)
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Take the session token from `AOC_SESSION` and the base URL from `AOC_BASE_URL`.
+    ///
+    /// Returns `None` without a session token, inputs being personal.
+    pub fn from_env() -> Option<Self> {
+        let session: String = env::var(SESSION_ENV)
+            .ok()
+            .filter(|token| !token.is_empty())?;
+        let base_url: String = env::var(BASE_URL_ENV).unwrap_or(String::from(DEFAULT_BASE_URL));
+        Some(HttpProvider::new(&base_url, &session))
+    }
+
+    pub fn input_url(&self, day: usize) -> String {
+        format!("{}/2024/day/{day}/input", self.base_url)
+    }
+}
+
+impl InputProvider for HttpProvider {
+    fn fetch(&self, day: usize) -> Result<String, AocError> {
+        let url: String = self.input_url(day);
+        // Never show the session token in errors
+        let response: ureq::Response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(code, _) => AocError::download(&url, format!("HTTP {code}")),
+                ureq::Error::Transport(transport) => {
+                    AocError::download(&url, transport.to_string())
+                }
+            })?;
+
+        let mut input: String = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut input)
+            .map_err(|error| AocError::download(&url, error.to_string()))?;
+        Ok(input)
+    }
+}
+
+/// Inputs read from the filesystem cache, those missing being fetched once from the remote.
+pub struct CachedProvider {
+    cache: FsCache,
+    remote: Option<Box<dyn InputProvider>>,
+}
+
+impl CachedProvider {
+    pub fn new(cache: FsCache, remote: Option<Box<dyn InputProvider>>) -> Self {
+        CachedProvider { cache, remote }
+    }
+
+    /// Cache in the data directory, downloading through HTTP when a session token is set.
+    pub fn from_env(data_dir: &Path) -> Self {
+        let remote: Option<Box<dyn InputProvider>> =
+            HttpProvider::from_env().map(|provider| Box::new(provider) as Box<dyn InputProvider>);
+        CachedProvider::new(FsCache::new(data_dir), remote)
+    }
+}
+
+impl InputProvider for CachedProvider {
+    fn fetch(&self, day: usize) -> Result<String, AocError> {
+        match &self.remote {
+            Some(remote) if !self.cache.contains(day) => {
+                let input: String = remote.fetch(day)?;
+                self.cache.store(day, &input)?;
+                Ok(input)
+            }
+            _ => self.cache.fetch(day),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use gif::{Encoder, Repeat};
+use once_cell::sync::OnceCell;
+
+use crate::cli::{FrameArgs, FrameFormat};
+use crate::error::AocError;
+use crate::toolbox::render::{Image, RenderTile, Scene};
+
+/// Line between two frames of a text recording.
+const FRAME_SEPARATOR: &str = "\u{c}\n";
+
+static RECORDER: OnceCell<FrameRecorder> = OnceCell::new();
+
+thread_local! {
+    /// Sequence receiving the frames recorded by the current thread, set by the runner per part.
+    static SEQUENCE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Snapshot of a simulation at one of its steps.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    pub text: String,
+    pub image: Image, // A pixel per cell, scaled on export
+}
+
+/// Frames recorded by the solutions through `record_frame!`, by sequence, e.g. `day14-part2`.
+///
+/// Recording is off unless the recorder is installed, solutions then skip building their scenes.
+pub struct FrameRecorder {
+    limit: usize,
+    sequences: Mutex<BTreeMap<String, VecDeque<Frame>>>,
+}
+
+impl FrameRecorder {
+    /// Beyond `limit` frames in a sequence, the oldest ones are dropped to keep the final state.
+    pub fn new(limit: usize) -> Self {
+        FrameRecorder {
+            limit: limit.max(1),
+            sequences: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Install the recorder for the whole process.
+    pub fn init(self) {
+        if RECORDER.set(self).is_err() {
+            panic!("The frame recorder is only installed once");
+        }
+    }
+
+    fn push(&self, sequence: String, frame: Frame) {
+        let mut sequences = self.sequences.lock().expect("No recording thread panics");
+        let frames: &mut VecDeque<Frame> = sequences.entry(sequence).or_default();
+        if frames.len() == self.limit {
+            frames.pop_front();
+        }
+        frames.push_back(frame);
+    }
+}
+
+/// Name of the sequence of a part, the example's name being included if any.
+pub fn sequence_name(day: usize, example: Option<&str>, part: usize) -> String {
+    match example {
+        None => format!("day{day:0>2}-part{part}"),
+        Some(name) => format!("day{day:0>2}-{name}-part{part}"),
+    }
+}
+
+/// Send the frames recorded by the current thread to a sequence, or nowhere with `None`.
+pub fn set_sequence(sequence: Option<String>) {
+    SEQUENCE.with(|current| *current.borrow_mut() = sequence);
+}
+
+/// Whether frames recorded by the current thread are kept.
+///
+/// Threads started by the solutions themselves do not belong to any sequence.
+pub fn is_recording() -> bool {
+    RECORDER.get().is_some() && SEQUENCE.with(|current| current.borrow().is_some())
+}
+
+pub fn record<T: RenderTile>(scene: &Scene<T>) {
+    let (Some(recorder), Some(sequence)) = (
+        RECORDER.get(),
+        SEQUENCE.with(|current| current.borrow().clone()),
+    ) else {
+        return;
+    };
+
+    recorder.push(
+        sequence,
+        Frame {
+            text: scene.to_text(),
+            image: scene.to_image(1),
+        },
+    );
+}
+
+/// Record a step of a simulation when recording, the scene being built only then.
+///
+/// ```ignore
+/// record_frame!(Scene::new(&grid).mark(guard, '^'));
+/// ```
+#[macro_export]
+macro_rules! record_frame {
+    ($scene:expr) => {
+        if $crate::recorder::is_recording() {
+            $crate::recorder::record(&$scene);
+        }
+    };
+}
+
+/// Recorded sequences, which are removed from the recorder.
+pub fn take_sequences() -> BTreeMap<String, Vec<Frame>> {
+    let Some(recorder) = RECORDER.get() else {
+        return BTreeMap::new();
+    };
+    let mut sequences = recorder
+        .sequences
+        .lock()
+        .expect("No recording thread panics");
+
+    std::mem::take(&mut *sequences)
+        .into_iter()
+        .map(|(name, frames)| (name, frames.into()))
+        .collect()
+}
+
+// Export
+
+/// Write each sequence into `dir` in the requested formats, returning the created files.
+///
+/// A sequence gives `<name>.gif`, `<name>/frame-NNNNN.png` files and/or `<name>.txt`.
+pub fn export(
+    sequences: &BTreeMap<String, Vec<Frame>>,
+    dir: &Path,
+    args: &FrameArgs,
+) -> Result<Vec<PathBuf>, AocError> {
+    fs::create_dir_all(dir).map_err(|error| AocError::render(dir, error.to_string()))?;
+    let mut created: Vec<PathBuf> = Vec::new();
+
+    for (name, frames) in sequences {
+        for format in &args.formats {
+            match format {
+                FrameFormat::Gif => {
+                    let path: PathBuf = dir.join(format!("{name}.gif"));
+                    write_gif(&path, frames, args.scale, args.delay)?;
+                    created.push(path);
+                }
+                FrameFormat::Png => {
+                    let frames_dir: PathBuf = dir.join(name);
+                    fs::create_dir_all(&frames_dir)
+                        .map_err(|error| AocError::render(&frames_dir, error.to_string()))?;
+                    for (index, frame) in frames.iter().enumerate() {
+                        let path: PathBuf = frames_dir.join(format!("frame-{:0>5}.png", index + 1));
+                        let png: Vec<u8> = frame
+                            .image
+                            .scaled(args.scale)
+                            .to_png()
+                            .map_err(|message| AocError::render(&path, message))?;
+                        fs::write(&path, png)
+                            .map_err(|error| AocError::render(&path, error.to_string()))?;
+                    }
+                    created.push(frames_dir);
+                }
+                FrameFormat::Text => {
+                    let path: PathBuf = dir.join(format!("{name}.txt"));
+                    let text: String = frames
+                        .iter()
+                        .map(|frame| frame.text.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(FRAME_SEPARATOR);
+                    fs::write(&path, text)
+                        .map_err(|error| AocError::render(&path, error.to_string()))?;
+                    created.push(path);
+                }
+            }
+        }
+    }
+
+    Ok(created)
+}
+
+fn write_gif(path: &Path, frames: &[Frame], scale: usize, delay: u64) -> Result<(), AocError> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+    let first: Image = first.image.scaled(scale);
+    let (Ok(width), Ok(height)) = (u16::try_from(first.width), u16::try_from(first.height)) else {
+        return Err(AocError::render(
+            path,
+            format!(
+                "{}x{} pixels is too large for a GIF, lower the scale",
+                first.width, first.height
+            ),
+        ));
+    };
+
+    let file: File =
+        File::create(path).map_err(|error| AocError::render(path, error.to_string()))?;
+    let mut encoder = Encoder::new(file, width, height, &[])
+        .map_err(|error| AocError::render(path, error.to_string()))?;
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(|error| AocError::render(path, error.to_string()))?;
+
+    // GIF delays are in hundredths of a second
+    let delay: u16 = (delay / 10).min(u16::MAX as u64) as u16;
+    for frame in frames {
+        let image: Image = frame.image.scaled(scale);
+        if (image.width, image.height) != (first.width, first.height) {
+            return Err(AocError::render(
+                path,
+                "Frames of a GIF must all have the same size",
+            ));
+        }
+        let mut gif_frame = gif::Frame::from_rgb_speed(width, height, &image.pixels, 10);
+        gif_frame.delay = delay;
+        encoder
+            .write_frame(&gif_frame)
+            .map_err(|error| AocError::render(path, error.to_string()))?;
+    }
+
+    Ok(())
+}
+
+// Playback
+
+/// Show the frames of a text recording one after the other in the terminal.
+///
+/// Frames follow each other at `fps`, or when Enter is pressed with `step`, only one out of
+/// `every` being shown.
+pub fn play(path: &Path, fps: f64, every: usize, step: bool) -> Result<(), AocError> {
+    let text: String = fs::read_to_string(path).map_err(|source| AocError::MissingInput {
+        path: path.display().to_string(),
+        source,
+    })?;
+    let frames: Vec<&str> = text.split(FRAME_SEPARATOR).collect();
+    let delay: Duration = Duration::from_secs_f64(1.0 / fps.max(0.1));
+    let mut stdin = io::stdin().lock();
+
+    for (index, frame) in frames.iter().enumerate().step_by(every.max(1)) {
+        // Clear the screen, then go back to its top left corner
+        print!("\x1b[2J\x1b[H{frame}");
+        println!("Frame {}/{}", index + 1, frames.len());
+        io::stdout().flush().ok();
+
+        match step {
+            true => {
+                stdin.read_line(&mut String::new()).ok();
+            }
+            false => thread::sleep(delay),
+        }
+    }
+
+    Ok(())
+}
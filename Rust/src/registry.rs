@@ -0,0 +1,54 @@
+use crate::aoc::Aoc24Solution;
+use crate::cli::DaySelection;
+
+/// Entry of the solutions registry, submitted by each day with `register_solution!`.
+pub struct Registration {
+    pub build: fn() -> Box<dyn Aoc24Solution>,
+}
+
+inventory::collect!(Registration);
+
+/// Register a solution, so that the runner finds it without any other change.
+macro_rules! register_solution {
+    ($solution:ty) => {
+        inventory::submit! {
+            $crate::registry::Registration {
+                build: || Box::new(<$solution>::default()),
+            }
+        }
+    };
+}
+
+pub(crate) use register_solution;
+
+/// Build every registered solution, sorted by day.
+pub fn all_solutions() -> Vec<Box<dyn Aoc24Solution>> {
+    let mut solutions: Vec<Box<dyn Aoc24Solution>> = inventory::iter::<Registration>
+        .into_iter()
+        .map(|registration| (registration.build)())
+        .collect();
+    solutions.sort_by_key(|solution| solution.get_day_number());
+    solutions
+}
+
+/// Build the registered solutions of the selected days, sorted by day.
+pub fn select_solutions(selection: &DaySelection) -> Vec<Box<dyn Aoc24Solution>> {
+    all_solutions()
+        .into_iter()
+        .filter(|solution| selection.contains(solution.get_day_number()))
+        .collect()
+}
+
+pub fn find_solution(day: usize) -> Option<Box<dyn Aoc24Solution>> {
+    all_solutions()
+        .into_iter()
+        .find(|solution| solution.get_day_number() == day)
+}
+
+/// Sorted numbers of the days having a solution.
+pub fn available_days() -> Vec<usize> {
+    all_solutions()
+        .iter()
+        .map(|solution| solution.get_day_number())
+        .collect()
+}
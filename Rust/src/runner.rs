@@ -0,0 +1,683 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use serde::Serialize;
+
+use crate::answers::{input_key, AnswerStore, CheckStatus};
+use crate::aoc::{Answer, Aoc24Solution, InputSource, Params, DEFAULT_EXAMPLE};
+use crate::cli::{ExampleSelection, OutputFormat, PartSelection};
+use crate::error::AocError;
+use crate::recorder::{self, sequence_name};
+use crate::registry::find_solution;
+
+/// Outcome of the parsing of a day's input.
+#[derive(Debug)]
+pub enum ParseOutcome {
+    Parsed(Duration),
+    Failed(AocError),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+/// Outcome of a single part of a day.
+#[derive(Debug)]
+pub enum PartOutcome {
+    Solved(Answer, Duration),
+    Unsolved, // No answer for this input
+    NotImplemented,
+    Skipped,
+    Failed(AocError),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl PartOutcome {
+    fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            PartOutcome::Failed(_) | PartOutcome::Panicked(_) | PartOutcome::TimedOut(_)
+        )
+    }
+}
+
+/// Outcome of a whole day, as shown in the summary table.
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: usize,
+    pub example: Option<String>, // None for the real input
+    pub parse: ParseOutcome,
+    pub part_one: PartOutcome,
+    pub part_two: PartOutcome,
+    pub checks: Option<[CheckStatus; 2]>, // Only filled when checking against known answers
+    pub wall: Duration,                   // From reading the input to solving the last part
+}
+
+impl DayReport {
+    /// Whether the day should make the whole run fail.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self.parse, ParseOutcome::Parsed(_))
+            || self.part_one.is_failure()
+            || self.part_two.is_failure()
+            || self
+                .checks
+                .iter()
+                .flatten()
+                .any(|status| matches!(status, CheckStatus::Fail(_)))
+    }
+
+    /// Compare the answers of both parts against the known ones.
+    pub fn check(&mut self, answers: &AnswerStore) {
+        let key: String = input_key(self.example.as_deref());
+        self.checks = Some([1, 2].map(|part| match self.part(part) {
+            PartOutcome::Solved(answer, _) => answers.check(self.day, &key, part, answer),
+            _ => CheckStatus::Unknown,
+        }));
+    }
+
+    /// Store the answers of both parts, returning how many were recorded.
+    pub fn record(&self, answers: &mut AnswerStore) -> usize {
+        let key: String = input_key(self.example.as_deref());
+        let mut recorded: usize = 0;
+        for part in [1, 2] {
+            if let PartOutcome::Solved(answer, _) = self.part(part) {
+                answers.record(self.day, &key, part, answer);
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+
+    /// Day number, followed by the example's name unless it is the default one.
+    pub fn label(&self) -> String {
+        match self.example.as_deref() {
+            None | Some(DEFAULT_EXAMPLE) => format!("{:0>2}", self.day),
+            Some(name) => format!("{:0>2} ({name})", self.day),
+        }
+    }
+
+    fn part(&self, part: usize) -> &PartOutcome {
+        match part {
+            1 => &self.part_one,
+            _ => &self.part_two,
+        }
+    }
+
+    fn check_status(&self, part: usize) -> Option<&CheckStatus> {
+        self.checks.as_ref().map(|checks| &checks[part - 1])
+    }
+}
+
+/// Inputs to run a solution on, `None` standing for the real input.
+///
+/// A named example which the solution does not declare selects nothing.
+pub fn select_inputs(
+    solution: &dyn Aoc24Solution,
+    examples: Option<&ExampleSelection>,
+) -> Vec<Option<&'static str>> {
+    match examples {
+        None => vec![None],
+        Some(selection) => selection
+            .select(&solution.get_examples())
+            .into_iter()
+            .map(Some)
+            .collect(),
+    }
+}
+
+/// Run every selected input of the solutions, on up to `jobs` days at once (`0` for one per core).
+///
+/// Days are independent, but the inputs of a same day run one after the other. Reports are in the
+/// order of the solutions whatever the number of jobs.
+///
+/// A day which timed out frees its job as soon as it is given up on, so that the next days still
+/// run. Its abandoned thread keeps running outside of the `jobs` limit.
+pub fn run_days(
+    solutions: Vec<Box<dyn Aoc24Solution>>,
+    source: &InputSource,
+    examples: Option<&ExampleSelection>,
+    overrides: &[(String, i64)],
+    parts: PartSelection,
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> Vec<DayReport> {
+    let run_inputs = |solution: Box<dyn Aoc24Solution>| -> Vec<DayReport> {
+        let day: usize = solution.get_day_number();
+        let inputs: Vec<Option<&str>> = select_inputs(solution.as_ref(), examples);
+        let mut solution: Option<Box<dyn Aoc24Solution>> = Some(solution);
+
+        inputs
+            .into_iter()
+            .map(|example| {
+                // A timed out solution may still be running, so each input gets its own
+                let solution: Box<dyn Aoc24Solution> = solution
+                    .take()
+                    .or_else(|| find_solution(day))
+                    .expect("The day has a registered solution");
+                run_day(solution, source, example, overrides, parts, timeout)
+            })
+            .collect()
+    };
+
+    if jobs == 1 {
+        return solutions.into_iter().flat_map(run_inputs).collect();
+    }
+
+    let pool: ThreadPool = ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("Worker threads should start");
+    pool.install(|| {
+        solutions
+            .into_par_iter()
+            .flat_map_iter(run_inputs)
+            .collect()
+    })
+}
+
+/// Step of a day, sent by the thread solving it.
+enum Step {
+    Parse(ParseOutcome),
+    Part(PartOutcome),
+}
+
+/// Parse then solve both parts of a solution, isolating errors and panics so that other days can
+/// still run.
+///
+/// The example is `None` for the real input, parameters overrides apply on top of its defaults.
+/// Parts which are not selected, or which the day does not have, are skipped.
+///
+/// The solution runs on its own thread, so that parsing or a part exceeding the timeout can be
+/// given up on. Such a thread cannot be stopped: it is abandoned, the next steps being skipped,
+/// and keeps running in the background until it finishes or the process exits.
+pub fn run_day(
+    solution: Box<dyn Aoc24Solution>,
+    source: &InputSource,
+    example: Option<&str>,
+    overrides: &[(String, i64)],
+    parts: PartSelection,
+    timeout: Option<Duration>,
+) -> DayReport {
+    let now: Instant = Instant::now();
+    let day: usize = solution.get_day_number();
+    let selected: Vec<usize> = parts.parts(solution.get_part_count());
+
+    let (sender, receiver) = mpsc::channel::<Step>();
+    let source: InputSource = source.clone();
+    let name: Option<String> = example.map(String::from);
+    let overrides: Vec<(String, i64)> = overrides.to_vec();
+    let steps: Vec<usize> = selected.clone();
+    thread::spawn(move || {
+        solve_steps(
+            solution,
+            &source,
+            name.as_deref(),
+            &overrides,
+            &steps,
+            &sender,
+        )
+    });
+
+    let parse: ParseOutcome = match wait_for_step(&receiver, timeout) {
+        Ok(Step::Parse(parse)) => parse,
+        Err(RecvTimeoutError::Timeout) => {
+            ParseOutcome::TimedOut(timeout.expect("Only a timeout expires"))
+        }
+        _ => ParseOutcome::Panicked(String::from("Solving thread stopped unexpectedly")),
+    };
+
+    let mut outcomes: [PartOutcome; 2] = [PartOutcome::Skipped, PartOutcome::Skipped];
+    if matches!(parse, ParseOutcome::Parsed(_)) {
+        for part in selected {
+            let outcome: &mut PartOutcome = &mut outcomes[part - 1];
+            *outcome = wait_for_part(&receiver, timeout);
+            if matches!(outcome, PartOutcome::TimedOut(_)) {
+                break;
+            }
+        }
+    }
+    let [part_one, part_two] = outcomes;
+
+    DayReport {
+        day,
+        example: example.map(String::from),
+        parse,
+        part_one,
+        part_two,
+        checks: None,
+        wall: now.elapsed(),
+    }
+}
+
+/// Run the steps of a day, stopping once the runner does not wait for them anymore.
+fn solve_steps(
+    mut solution: Box<dyn Aoc24Solution>,
+    source: &InputSource,
+    example: Option<&str>,
+    overrides: &[(String, i64)],
+    parts: &[usize],
+    sender: &Sender<Step>,
+) {
+    let parse: ParseOutcome = run_parse(solution.as_mut(), source, example);
+    let is_parsed: bool = matches!(parse, ParseOutcome::Parsed(_));
+    if sender.send(Step::Parse(parse)).is_err() || !is_parsed {
+        return;
+    }
+
+    let day: usize = solution.get_day_number();
+    for &part in parts {
+        recorder::set_sequence(Some(sequence_name(day, example, part)));
+        // Invalid parameters make each part fail, rather than the parsing
+        let outcome: PartOutcome =
+            match Params::resolve(&solution.get_params(), example.is_some(), overrides) {
+                Ok(params) => run_part(|| solution.solve_part(part, &params)),
+                Err(error) => PartOutcome::Failed(error),
+            };
+        recorder::set_sequence(None);
+        if sender.send(Step::Part(outcome)).is_err() {
+            return;
+        }
+    }
+}
+
+fn wait_for_step(
+    receiver: &Receiver<Step>,
+    timeout: Option<Duration>,
+) -> Result<Step, RecvTimeoutError> {
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    }
+}
+
+fn wait_for_part(receiver: &Receiver<Step>, timeout: Option<Duration>) -> PartOutcome {
+    match wait_for_step(receiver, timeout) {
+        Ok(Step::Part(outcome)) => outcome,
+        Err(RecvTimeoutError::Timeout) => {
+            PartOutcome::TimedOut(timeout.expect("Only a timeout expires"))
+        }
+        _ => PartOutcome::Panicked(String::from("Solving thread stopped unexpectedly")),
+    }
+}
+
+/// Read then parse the input of a solution, only the parsing being timed.
+fn run_parse(
+    solution: &mut dyn Aoc24Solution,
+    source: &InputSource,
+    example: Option<&str>,
+) -> ParseOutcome {
+    let input: String = match solution.get_data(source, example) {
+        Ok(input) => input,
+        Err(error) => return ParseOutcome::Failed(error),
+    };
+
+    match run_timed(|| solution.parse(&input)) {
+        (Ok(Ok(())), elapsed) => ParseOutcome::Parsed(elapsed),
+        (Ok(Err(error)), _) => ParseOutcome::Failed(error),
+        (Err(message), _) => ParseOutcome::Panicked(message),
+    }
+}
+
+fn run_part<F: FnOnce() -> Result<Answer, AocError>>(part: F) -> PartOutcome {
+    match run_timed(part) {
+        (Ok(Ok(Answer::Unsolved)), _) => PartOutcome::Unsolved,
+        (Ok(Ok(Answer::NotImplemented)), _) => PartOutcome::NotImplemented,
+        (Ok(Ok(answer)), elapsed) => PartOutcome::Solved(answer, elapsed),
+        (Ok(Err(error)), _) => PartOutcome::Failed(error),
+        (Err(message), _) => PartOutcome::Panicked(message),
+    }
+}
+
+/// Time a step, catching its panic if any.
+fn run_timed<T, F: FnOnce() -> T>(step: F) -> (Result<T, String>, Duration) {
+    let now: Instant = Instant::now();
+    let result = catch_unwind(AssertUnwindSafe(step));
+    let elapsed: Duration = now.elapsed();
+
+    let result = result.map_err(|payload| panic_message(payload.as_ref()));
+    (result, elapsed)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Unknown panic")
+    }
+}
+
+// Single day
+
+/// Print the outcome of a single day, line by line.
+pub fn print_day(report: &DayReport) {
+    let prefix: String = format!("[Day {}]", report.label());
+
+    match &report.parse {
+        ParseOutcome::Parsed(elapsed) => {
+            println!("{prefix} Parsed in {} us", elapsed.as_micros())
+        }
+        ParseOutcome::Failed(error) => {
+            eprintln!("{}", format!("{prefix} Parse failed: {error}").red())
+        }
+        ParseOutcome::Panicked(message) => {
+            eprintln!("{}", format!("{prefix} Parse panicked: {message}").red())
+        }
+        ParseOutcome::TimedOut(timeout) => {
+            eprintln!(
+                "{}",
+                format!("{prefix} Parse timed out after {timeout:?}").red()
+            )
+        }
+    }
+
+    for part in [1, 2] {
+        match report.part(part) {
+            PartOutcome::Solved(answer, elapsed) => {
+                let check: String = match report.check_status(part) {
+                    None => String::new(),
+                    Some(status) => format!(" [{}]", format_check(status)),
+                };
+                println!(
+                    "{prefix} Part {part}: {} in {} us{check}",
+                    answer.to_string().cyan(),
+                    elapsed.as_micros()
+                );
+            }
+            PartOutcome::Unsolved => println!(
+                "{prefix} Part {part}: {}",
+                "Unsolved for this input".yellow()
+            ),
+            PartOutcome::NotImplemented if part == 1 => {
+                println!(
+                    "{prefix} Part 1: {}",
+                    Answer::NotImplemented.to_string().cyan()
+                )
+            }
+            PartOutcome::NotImplemented => println!("No part two yet"),
+            PartOutcome::Skipped if report.day == 25 && part == 2 => println!("Merry Christmas !"),
+            PartOutcome::Skipped => {}
+            PartOutcome::Failed(error) => {
+                eprintln!("{}", format!("{prefix} Part {part} failed: {error}").red())
+            }
+            PartOutcome::Panicked(message) => {
+                eprintln!(
+                    "{}",
+                    format!("{prefix} Part {part} panicked: {message}").red()
+                )
+            }
+            PartOutcome::TimedOut(timeout) => {
+                eprintln!(
+                    "{}",
+                    format!("{prefix} Part {part} timed out after {timeout:?}").red()
+                )
+            }
+        }
+    }
+}
+
+fn format_check(status: &CheckStatus) -> String {
+    match status {
+        CheckStatus::Pass => "pass".green().to_string(),
+        CheckStatus::Fail(expected) => format!("fail (expected {expected})").red().to_string(),
+        CheckStatus::Unknown => "unknown".yellow().to_string(),
+    }
+}
+
+// Summary
+
+const ANSWER_WIDTH: usize = 20;
+const TIME_WIDTH: usize = 12;
+const CHECK_WIDTH: usize = 7;
+
+pub fn print_summary(reports: &[DayReport]) {
+    let with_checks: bool = reports.iter().any(|report| report.checks.is_some());
+    // Wider when named examples are shown
+    let day_width: usize = reports
+        .iter()
+        .map(|report| report.label().len())
+        .fold(5, usize::max);
+
+    let mut header: String = format!(
+        "{:<day_width$} | {:>TIME_WIDTH$} | {:<ANSWER_WIDTH$} | {:>TIME_WIDTH$} | {:<ANSWER_WIDTH$} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$} | ",
+        "Day", "Parse", "Part 1", "Time", "Part 2", "Time", "Wall"
+    );
+    if with_checks {
+        header += &format!(
+            "{:<CHECK_WIDTH$} | {:<CHECK_WIDTH$} | ",
+            "Check 1", "Check 2"
+        );
+    }
+    header += "Status";
+    println!("{header}");
+    println!("{}", "-".repeat(header.len()));
+
+    for report in reports {
+        let parse_time: String = match report.parse {
+            ParseOutcome::Parsed(elapsed) => format!("{} us", elapsed.as_micros()),
+            _ => String::new(),
+        };
+        let (answer_one, time_one): (String, String) = format_outcome(&report.part_one);
+        let (answer_two, time_two): (String, String) = format_outcome(&report.part_two);
+
+        let status: String = match (&report.parse, &report.part_one, &report.part_two) {
+            (ParseOutcome::Failed(error), _, _) => format!("Parse failed: {error}"),
+            (ParseOutcome::Panicked(message), _, _) => format!("Parse panicked: {message}"),
+            (ParseOutcome::TimedOut(timeout), _, _) => format!("Parse timed out after {timeout:?}"),
+            (_, PartOutcome::Failed(error), _) => format!("Part 1 failed: {error}"),
+            (_, PartOutcome::Panicked(message), _) => format!("Part 1 panicked: {message}"),
+            (_, _, PartOutcome::Failed(error)) => format!("Part 2 failed: {error}"),
+            (_, _, PartOutcome::Panicked(message)) => format!("Part 2 panicked: {message}"),
+            (_, PartOutcome::TimedOut(timeout), _) => format!("Part 1 timed out after {timeout:?}"),
+            (_, _, PartOutcome::TimedOut(timeout)) => format!("Part 2 timed out after {timeout:?}"),
+            _ => match (report.check_status(1), report.check_status(2)) {
+                (Some(CheckStatus::Fail(expected)), _) => format!("Part 1 expected {expected}"),
+                (_, Some(CheckStatus::Fail(expected))) => format!("Part 2 expected {expected}"),
+                _ => String::from("OK"),
+            },
+        };
+        let status: String = match report.is_failure() {
+            true => status.red().to_string(),
+            false => status.green().to_string(),
+        };
+
+        let checks: String = match with_checks {
+            false => String::new(),
+            true => [1, 2]
+                .map(|part| match report.check_status(part) {
+                    None | Some(CheckStatus::Unknown) => {
+                        format!("{:<CHECK_WIDTH$}", "unknown").yellow()
+                    }
+                    Some(CheckStatus::Pass) => format!("{:<CHECK_WIDTH$}", "pass").green(),
+                    Some(CheckStatus::Fail(_)) => format!("{:<CHECK_WIDTH$}", "fail").red(),
+                })
+                .map(|check| format!("{check} | "))
+                .concat(),
+        };
+
+        // Pad before coloring, escape codes would be counted in the width otherwise
+        println!(
+            "{:<day_width$} | {:>TIME_WIDTH$} | {} | {:>TIME_WIDTH$} | {} | {:>TIME_WIDTH$} | {:>TIME_WIDTH$} | {checks}{status}",
+            report.label(),
+            parse_time,
+            format!("{answer_one:<ANSWER_WIDTH$}").cyan(),
+            time_one,
+            format!("{answer_two:<ANSWER_WIDTH$}").cyan(),
+            time_two,
+            format!("{} us", report.wall.as_micros()),
+        );
+    }
+
+    let parse_total: Duration = reports
+        .iter()
+        .filter_map(|report| match report.parse {
+            ParseOutcome::Parsed(elapsed) => Some(elapsed),
+            _ => None,
+        })
+        .sum();
+    let solve_total: Duration = reports
+        .iter()
+        .flat_map(|report| [&report.part_one, &report.part_two])
+        .filter_map(|outcome| match outcome {
+            PartOutcome::Solved(_, elapsed) => Some(*elapsed),
+            _ => None,
+        })
+        .sum();
+    // With several jobs, this is about the wall time of the whole run
+    let slowest: Duration = reports
+        .iter()
+        .map(|report| report.wall)
+        .max()
+        .unwrap_or_default();
+    let failures: usize = reports.iter().filter(|report| report.is_failure()).count();
+
+    println!(
+        "\n{} day(s) run in {} us ({} us parsing, slowest day {} us), {} failure(s)",
+        reports.len(),
+        (parse_total + solve_total).as_micros(),
+        parse_total.as_micros(),
+        slowest.as_micros(),
+        failures
+    );
+}
+
+fn format_outcome(outcome: &PartOutcome) -> (String, String) {
+    match outcome {
+        PartOutcome::Solved(answer, elapsed) => {
+            (answer.to_string(), format!("{} us", elapsed.as_micros()))
+        }
+        PartOutcome::Unsolved => (String::from("Unsolved"), String::new()),
+        PartOutcome::NotImplemented => (String::from("Not implemented"), String::new()),
+        PartOutcome::TimedOut(timeout) => (String::from("-"), format!("> {timeout:?}")),
+        PartOutcome::Skipped | PartOutcome::Failed(_) | PartOutcome::Panicked(_) => {
+            (String::from("-"), String::new())
+        }
+    }
+}
+
+// Machine-readable output
+
+/// Outcome of a single part, flattened for JSON and CSV outputs.
+#[derive(Debug, Serialize)]
+pub struct RunRecord {
+    pub day: usize,
+    pub part: usize,
+    pub input: String, // Name of the example, or "real"
+    pub status: &'static str,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u128>,
+    pub wall_ns: u128, // Of the whole day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DayReport {
+    /// Flatten both parts into records, a parsing failure being reported on each part.
+    pub fn to_records(&self) -> Vec<RunRecord> {
+        let parse_error: Option<String> = match &self.parse {
+            ParseOutcome::Parsed(_) => None,
+            ParseOutcome::Failed(error) => Some(error.to_string()),
+            ParseOutcome::Panicked(message) => Some(message.clone()),
+            ParseOutcome::TimedOut(timeout) => Some(format!("Parse timed out after {timeout:?}")),
+        };
+
+        [1, 2]
+            .map(|part| {
+                let (status, answer, elapsed_ns, error) = match self.part(part) {
+                    PartOutcome::Solved(answer, elapsed) => (
+                        "solved",
+                        Some(answer.to_string()),
+                        Some(elapsed.as_nanos()),
+                        None,
+                    ),
+                    PartOutcome::Unsolved => ("unsolved", None, None, None),
+                    PartOutcome::NotImplemented => ("not_implemented", None, None, None),
+                    PartOutcome::Skipped if parse_error.is_some() => {
+                        ("failed", None, None, parse_error.clone())
+                    }
+                    PartOutcome::Skipped => ("skipped", None, None, None),
+                    PartOutcome::Failed(error) => ("failed", None, None, Some(error.to_string())),
+                    PartOutcome::Panicked(message) => {
+                        ("panicked", None, None, Some(message.clone()))
+                    }
+                    PartOutcome::TimedOut(timeout) => (
+                        "timed_out",
+                        None,
+                        Some(timeout.as_nanos()),
+                        Some(format!("Timed out after {timeout:?}")),
+                    ),
+                };
+                let check: Option<&'static str> =
+                    self.check_status(part).map(|status| match status {
+                        CheckStatus::Pass => "pass",
+                        CheckStatus::Fail(_) => "fail",
+                        CheckStatus::Unknown => "unknown",
+                    });
+
+                RunRecord {
+                    day: self.day,
+                    part,
+                    input: self.example.clone().unwrap_or(String::from("real")),
+                    status,
+                    answer,
+                    elapsed_ns,
+                    wall_ns: self.wall.as_nanos(),
+                    check,
+                    error,
+                }
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Print the outcome of every part as JSON or CSV, text output being the summary table.
+pub fn print_reports(reports: &[DayReport], format: OutputFormat) {
+    let records: Vec<RunRecord> = reports.iter().flat_map(DayReport::to_records).collect();
+
+    match format {
+        OutputFormat::Text => print_summary(reports),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("Records are serializable")
+        ),
+        OutputFormat::Csv => {
+            println!("day,part,input,status,answer,elapsed_ns,wall_ns,check,error");
+            for record in records {
+                println!(
+                    "{},{},{},{},{},{},{},{},{}",
+                    record.day,
+                    record.part,
+                    record.input,
+                    record.status,
+                    csv_field(record.answer.as_deref()),
+                    record
+                        .elapsed_ns
+                        .map_or(String::new(), |nanos| nanos.to_string()),
+                    record.wall_ns,
+                    record.check.unwrap_or_default(),
+                    csv_field(record.error.as_deref()),
+                );
+            }
+        }
+    }
+}
+
+/// Quote a CSV field when needed, answers such as `co,de,ka,ta` containing commas.
+fn csv_field(value: Option<&str>) -> String {
+    match value {
+        None => String::new(),
+        Some(value) if value.contains([',', '"', '\n']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_string(),
+    }
+}
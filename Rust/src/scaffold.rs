@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::AocError;
+
+// Templates, `NN` being replaced by the padded day number
+
+const MOD_TEMPLATE: &str = "mod model;
+mod parse;
+mod solve;
+
+use crate::aoc::{Answer, Aoc24Solution, Example, Params, DEFAULT_EXAMPLE};
+use crate::dayNN::model::PuzzleInput;
+use crate::dayNN::parse::parse_input;
+use crate::dayNN::solve::{solve_part_one, solve_part_two};
+use crate::error::AocError;
+use crate::registry::register_solution;
+
+#[derive(Default)]
+pub struct DayNN {
+    input: PuzzleInput,
+}
+
+register_solution!(DayNN);
+
+impl Aoc24Solution for DayNN {
+    fn get_day_number(&self) -> usize {
+        DAY
+    }
+
+    fn get_examples(&self) -> Vec<Example> {
+        vec![Example::new(DEFAULT_EXAMPLE, None, None)]
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), AocError> {
+        self.input = parse_input(input)?;
+        Ok(())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_one(&self.input))
+    }
+
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(solve_part_two(&self.input))
+    }
+}
+";
+
+const PARSE_TEMPLATE: &str = "use crate::dayNN::model::PuzzleInput;
+use crate::error::AocError;
+
+pub fn parse_input(_input: &str) -> Result<PuzzleInput, AocError> {
+    Ok(PuzzleInput::default())
+}
+";
+
+const MODEL_TEMPLATE: &str = "#[derive(Debug, Default)]
+pub struct PuzzleInput {}
+";
+
+const SOLVE_TEMPLATE: &str = "use crate::aoc::Answer;
+use crate::dayNN::model::PuzzleInput;
+
+pub fn solve_part_one(_input: &PuzzleInput) -> Answer {
+    Answer::NotImplemented
+}
+
+pub fn solve_part_two(_input: &PuzzleInput) -> Answer {
+    Answer::NotImplemented
+}
+";
+
+/// Generate the module of a new day under `src_dir`, declare it in `lib.rs`, then create its empty
+/// input and example files under `data_dir`.
+///
+/// Nothing is written when the day already exists. Returns the created files.
+pub fn scaffold_day(day: usize, src_dir: &Path, data_dir: &Path) -> Result<Vec<PathBuf>, AocError> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::scaffold(
+            src_dir,
+            format!("Day {day} is not an Advent of Code day"),
+        ));
+    }
+
+    let name: String = format!("day{day:0>2}");
+    let module_dir: PathBuf = src_dir.join(&name);
+    let lib_path: PathBuf = src_dir.join("lib.rs");
+    let lib: String = read(&lib_path)?;
+
+    let mod_line: String = format!("pub mod {name};");
+    if module_dir.exists() || lib.lines().any(|line| line.trim() == mod_line) {
+        return Err(AocError::scaffold(&module_dir, "Day already exists"));
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("NN", &format!("{day:0>2}"))
+            .replace("DAY", &day.to_string())
+    };
+    let sources: [(&str, String); 4] = [
+        ("mod.rs", fill(MOD_TEMPLATE)),
+        ("parse.rs", fill(PARSE_TEMPLATE)),
+        ("model.rs", fill(MODEL_TEMPLATE)),
+        ("solve.rs", fill(SOLVE_TEMPLATE)),
+    ];
+
+    let mut created: Vec<PathBuf> = Vec::new();
+    create_dir(&module_dir)?;
+    for (file_name, content) in sources {
+        let path: PathBuf = module_dir.join(file_name);
+        write(&path, &content)?;
+        created.push(path);
+    }
+
+    write(&lib_path, &insert_mod_line(&lib, &mod_line))?;
+
+    // Data files may already be there, e.g. when downloaded beforehand
+    for sub_dir in ["inputs", "tests"] {
+        let path: PathBuf = data_dir.join(sub_dir).join(format!("{name}.txt"));
+        if !path.exists() {
+            create_dir(&data_dir.join(sub_dir))?;
+            write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+/// Insert the module declaration among the other days, keeping them sorted.
+fn insert_mod_line(lib: &str, mod_line: &str) -> String {
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let is_day_line = |line: &&str| line.starts_with("pub mod day");
+
+    let index: usize = match lines.iter().rposition(is_day_line) {
+        // Day modules are zero padded, so they sort as strings
+        Some(last) => lines
+            .iter()
+            .position(|line| is_day_line(line) && *line > mod_line)
+            .unwrap_or(last + 1),
+        None => lines.len(),
+    };
+    lines.insert(index, mod_line);
+
+    lines.join("\n") + "\n"
+}
+
+fn read(path: &Path) -> Result<String, AocError> {
+    fs::read_to_string(path).map_err(|error| AocError::scaffold(path, error.to_string()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), AocError> {
+    fs::write(path, content).map_err(|error| AocError::scaffold(path, error.to_string()))
+}
+
+fn create_dir(path: &Path) -> Result<(), AocError> {
+    fs::create_dir_all(path).map_err(|error| AocError::scaffold(path, error.to_string()))
+}
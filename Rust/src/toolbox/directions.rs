@@ -71,6 +71,20 @@ impl Direction {
         }
     }
 
+    /// Arrow pointing toward the direction, as drawn in puzzle inputs for straight ones.
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::N => '^',
+            Direction::NE => '↗',
+            Direction::E => '>',
+            Direction::SE => '↘',
+            Direction::S => 'v',
+            Direction::SW => '↙',
+            Direction::W => '<',
+            Direction::NW => '↖',
+        }
+    }
+
     // Neighbours
 
     pub fn neighbours4() -> Vec<Self> {
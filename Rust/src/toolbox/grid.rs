@@ -1,10 +1,13 @@
+use crate::error::AocError;
 use crate::toolbox::coordinates::Coordinates;
-use colored::Colorize;
+use crate::toolbox::parsing::unexpected_char;
 use faer::sparse::ops::sub;
 use itertools::Itertools;
+use std::collections::BTreeMap;
 use std::fmt::{format, Debug};
+use std::ops::{Index, IndexMut};
 
-/// Grid of data.
+/// Grid of data, stored row after row in a single vector.
 ///
 /// Orientation:
 /// +--------> y  (width)
@@ -12,197 +15,394 @@ use std::fmt::{format, Debug};
 /// |
 /// V
 /// x (depth)
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Grid<T> {
-    pub(crate) data: Vec<Vec<T>>,
+    data: Vec<T>,
+    depth: usize,
+    width: usize,
 }
 
 impl<T> Grid<T> {
-    pub fn new(data: Vec<Vec<T>>) -> Self {
-        Self { data }
+    /// Build a grid from its rows, which must all have the same length, see [`Grid::try_new`].
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        Self::try_new(rows).unwrap_or_else(|error| panic!("{error}"))
     }
 
-    /// Get the depth of the grid (length on x-axis / number of sub-arrays)
+    /// Build a grid from its rows, a row of a different length being reported with its position.
+    pub fn try_new(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let depth: usize = rows.len();
+        let width: usize = rows.first().map_or(0, Vec::len);
+        if let Some((x, row)) = rows.iter().find_position(|row| row.len() != width) {
+            return Err(AocError::parse(
+                x + 1,
+                row.len().min(width) + 1,
+                format!("Expected a row of {width} values, got {}", row.len()),
+            ));
+        }
+
+        Ok(Self {
+            data: rows.into_iter().flatten().collect(),
+            depth,
+            width,
+        })
+    }
+
+    /// Build a grid from its values, row after row.
+    pub fn from_vec(data: Vec<T>, depth: usize, width: usize) -> Self {
+        assert_eq!(data.len(), depth * width, "Grid of {depth}x{width} values");
+        Self { data, depth, width }
+    }
+
+    /// Build a grid from the value of each of its coordinates, row after row.
+    pub fn from_fn<F: FnMut(Coordinates) -> T>(depth: usize, width: usize, value_at: F) -> Self {
+        Self {
+            data: coordinates_of(depth, width).map(value_at).collect(),
+            depth,
+            width,
+        }
+    }
+
+    /// Get the depth of the grid (length on x-axis / number of rows)
     pub fn depth(&self) -> usize {
-        self.data.len()
+        self.depth
     }
 
-    /// Get the width of the grid (length on y-axis / length of sub-arrays)
+    /// Get the width of the grid (length on y-axis / length of rows)
     pub fn width(&self) -> usize {
-        self.data.first().expect("Empty grid").len()
+        self.width
     }
 
     pub fn is_in_bounds(&self, coordinates: &Coordinates) -> bool {
         0 <= coordinates.x
-            && (coordinates.x as usize) < self.depth()
+            && (coordinates.x as usize) < self.depth
             && 0 <= coordinates.y
-            && (coordinates.y as usize) < self.width()
+            && (coordinates.y as usize) < self.width
+    }
+
+    /// Position of the value at the given coordinates in the data.
+    fn index_of(&self, coordinates: &Coordinates) -> Option<usize> {
+        match self.is_in_bounds(coordinates) {
+            false => None,
+            true => Some(coordinates.x as usize * self.width + coordinates.y as usize),
+        }
     }
 
     /// Replace a value in the data.
     ///
     /// Returns `true` if the change occurred, `false` otherwise.
     pub fn replace(&mut self, coordinates: &Coordinates, value: T) -> bool {
-        if !self.is_in_bounds(coordinates) {
-            return false;
+        match self.get_mut(coordinates) {
+            None => false,
+            Some(current) => {
+                *current = value;
+                true
+            }
         }
-
-        self.data[coordinates.x as usize][coordinates.y as usize] = value;
-        true
     }
 
     pub fn get_coordinates_vec(&self) -> Vec<Coordinates> {
-        let mut coordinates: Vec<Coordinates> = Vec::with_capacity(self.depth() * self.width());
-
-        for i in 0..self.depth() {
-            for j in 0..self.width() {
-                coordinates.push(Coordinates {
-                    x: i as i32,
-                    y: j as i32,
-                });
-            }
-        }
-
-        coordinates
+        self.coordinates().collect()
     }
 
     pub fn get_ref(&self, coordinates: &Coordinates) -> Option<&T> {
-        match self.is_in_bounds(coordinates) {
-            false => None,
-            true => Some(&self.data[coordinates.x as usize][coordinates.y as usize]),
-        }
+        self.index_of(coordinates).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, coordinates: &Coordinates) -> Option<&mut T> {
+        self.index_of(coordinates)
+            .map(|index| &mut self.data[index])
+    }
+
+    /// Values of the x-th row, from west to east, `None` if out of the grid.
+    pub fn row(&self, x: usize) -> Option<&[T]> {
+        (x < self.depth).then(|| &self.data[x * self.width..(x + 1) * self.width])
+    }
+
+    pub fn row_mut(&mut self, x: usize) -> Option<&mut [T]> {
+        (x < self.depth).then(|| &mut self.data[x * self.width..(x + 1) * self.width])
+    }
+
+    /// Values of the y-th column, from north to south, `None` if out of the grid.
+    pub fn column(&self, y: usize) -> Option<impl Iterator<Item = &T>> {
+        (y < self.width).then(|| self.data.iter().skip(y).step_by(self.width))
     }
 
     // Iteration
 
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Chunks of an empty grid must not be empty
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinates> {
+        coordinates_of(self.depth, self.width)
+    }
+
     pub fn enumerate(&self) -> impl Iterator<Item = (Coordinates, &T)> {
-        self.data.iter().enumerate().flat_map(|(x_idx, sub_arr)| {
-            sub_arr.iter().enumerate().map(move |(y_idx, elmt)| {
-                (
-                    Coordinates {
-                        x: x_idx as i32,
-                        y: y_idx as i32,
-                    },
-                    elmt,
-                )
-            })
-        })
+        self.coordinates().zip(self.data.iter())
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data.iter().flatten()
+        self.data.iter()
     }
-}
 
-impl Grid<char> {
-    pub fn from_string(value: &str) -> Self {
-        Self {
-            data: value.lines().map(|line| line.chars().collect()).collect(),
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    /// Grid of the same shape, with each value converted.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, convert: F) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(convert).collect(),
+            depth: self.depth,
+            width: self.width,
         }
     }
 }
 
-impl<T: Clone> Grid<T> {
-    pub fn fill(value: T, depth: usize, width: usize) -> Self {
-        let mut sub_data: Vec<T> = Vec::with_capacity(width);
-        for _ in 0..width {
-            sub_data.push(value.clone());
+fn coordinates_of(depth: usize, width: usize) -> impl Iterator<Item = Coordinates> {
+    (0..depth).flat_map(move |x| {
+        (0..width).map(move |y| Coordinates {
+            x: x as i32,
+            y: y as i32,
+        })
+    })
+}
+
+impl<T> Index<Coordinates> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinates: Coordinates) -> &T {
+        match self.index_of(&coordinates) {
+            Some(index) => &self.data[index],
+            None => panic!(
+                "{coordinates:?} is out of a {}x{} grid",
+                self.depth, self.width
+            ),
         }
+    }
+}
 
-        let mut data: Vec<Vec<T>> = Vec::with_capacity(depth);
-        for _ in 0..depth {
-            data.push(sub_data.clone());
+impl<T> IndexMut<Coordinates> for Grid<T> {
+    fn index_mut(&mut self, coordinates: Coordinates) -> &mut T {
+        match self.index_of(&coordinates) {
+            Some(index) => &mut self.data[index],
+            None => panic!(
+                "{coordinates:?} is out of a {}x{} grid",
+                self.depth, self.width
+            ),
         }
+    }
+}
 
-        Self { data }
+impl Grid<char> {
+    /// Grid of the characters of each line, which must all have the same length.
+    ///
+    /// Inputs are rather parsed with [`Grid::parse_with`], which reports ragged lines.
+    pub fn from_string(value: &str) -> Self {
+        Self::new(value.lines().map(|line| line.chars().collect()).collect())
     }
 }
 
-impl<T: Debug> Grid<T> {
-    pub fn show_surroundings(&self, coordinates: &Coordinates, depth: usize) {
-        let min_x: i32 = coordinates.x - depth as i32;
-        let max_x: i32 = coordinates.x + depth as i32;
-        let min_y: i32 = coordinates.y - depth as i32;
-        let max_y: i32 = coordinates.y + depth as i32;
+/// Tile of a grid, parsed from a single character.
+pub trait FromGridChar: Sized {
+    /// Tile of the character, `None` if the character is unexpected.
+    fn from_grid_char(chr: char) -> Option<Self>;
+}
 
-        let mut result: String = String::new();
+/// Positions of the marker characters met while parsing a grid, such as a starting point.
+#[derive(Clone, Debug, Default)]
+pub struct Markers {
+    positions: BTreeMap<char, Vec<Coordinates>>,
+}
 
-        for x in min_x..=max_x {
-            for y in min_y..=max_y {
-                match self.get_ref(&Coordinates { x, y }) {
-                    None => result.push(' '),
-                    Some(val) => result.push_str(&format!("{val:?}")),
-                }
-            }
-            result.push('\n');
-        }
+impl Markers {
+    /// Every position of the marker, in reading order.
+    pub fn all(&self, marker: char) -> &[Coordinates] {
+        self.positions.get(&marker).map_or(&[], Vec::as_slice)
+    }
 
-        println!("{result}");
+    /// Position of a marker which appears exactly once.
+    pub fn single(&self, marker: char) -> Result<Coordinates, AocError> {
+        match self.all(marker) {
+            [position] => Ok(*position),
+            [] => Err(AocError::invalid_state(format!(
+                "No '{marker}' in the grid"
+            ))),
+            positions => Err(AocError::invalid_state(format!(
+                "Expected a single '{marker}' in the grid, found {}",
+                positions.len()
+            ))),
+        }
     }
+}
 
-    pub fn show_path(&self, path: &[Coordinates], path_char: char) {
-        for x in 0..self.depth() {
-            for y in 0..self.width() {
-                let pos: Coordinates = Coordinates {
-                    x: x as i32,
-                    y: y as i32,
-                };
-                if path.contains(&pos) {
-                    print!("{}", &path_char.to_string().green());
-                } else {
-                    print!("{}", &format!("{:?}", self.get_ref(&pos).unwrap()))
+impl<T> Grid<T> {
+    /// Parse a grid from its lines, one tile per character.
+    ///
+    /// Markers are still turned into tiles, their positions being collected on the side. Unexpected
+    /// characters and lines of different lengths are reported with their position.
+    pub fn parse_with<F>(
+        input: &str,
+        markers: &[char],
+        mut tile_of: F,
+    ) -> Result<(Self, Markers), AocError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut data: Vec<T> = Vec::with_capacity(input.len());
+        let mut found: Markers = Markers::default();
+        let mut width: Option<usize> = None;
+        let mut depth: usize = 0;
+
+        for (line_index, line) in input.lines().enumerate() {
+            let mut line_width: usize = 0;
+            for (chr_index, chr) in line.chars().enumerate() {
+                let tile: T =
+                    tile_of(chr).ok_or_else(|| unexpected_char(line_index, chr_index, chr))?;
+                if markers.contains(&chr) {
+                    let position: Coordinates = Coordinates {
+                        x: line_index as i32,
+                        y: chr_index as i32,
+                    };
+                    found.positions.entry(chr).or_default().push(position);
                 }
+                data.push(tile);
+                line_width += 1;
             }
 
-            println!();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(AocError::parse(
+                        line_index + 1,
+                        line_width.min(width) + 1,
+                        format!("Expected a line of {width} tiles, got {line_width}"),
+                    ))
+                }
+                Some(_) => {}
+            }
+            depth += 1;
         }
+
+        Ok((Self::from_vec(data, depth, width.unwrap_or(0)), found))
     }
+}
 
-    pub fn display(&self) {
-        self.display_with_mark(None)
+impl<T: FromGridChar> Grid<T> {
+    /// Parse a grid of tiles, see [`Grid::parse_with`].
+    pub fn parse(input: &str, markers: &[char]) -> Result<(Self, Markers), AocError> {
+        Self::parse_with(input, markers, T::from_grid_char)
     }
+}
 
-    pub fn display_with_mark(&self, marked_position: Option<Coordinates>) {
-        // Build horizontal indexes bar
-        let mut horizontal_indexes: String = String::with_capacity(self.width() + 5);
-        horizontal_indexes.push_str("     "); // Three spaces used by vertical bar
-        for i in 0..self.width() {
-            horizontal_indexes.push_str(&format!("{}", i % 10));
+impl<T: Clone> Grid<T> {
+    pub fn fill(value: T, depth: usize, width: usize) -> Self {
+        Self {
+            data: vec![value; depth * width],
+            depth,
+            width,
         }
+    }
 
-        // Now construct the grid
+    // Transforms, each returning a new grid
 
-        let mut result: String = String::new();
+    /// Value at the given position, which must be in bounds.
+    fn at(&self, x: usize, y: usize) -> T {
+        self.data[x * self.width + y].clone()
+    }
 
-        // Add upper horizontal indexes bar
-        result.push_str(&format!("{horizontal_indexes}\n"));
+    /// Swap the axes, rows becoming columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.width, self.depth, |c| {
+            self.at(c.y as usize, c.x as usize)
+        })
+    }
 
-        // Add elements line by line (and insert vertical bars)
-        for x in 0..self.depth() {
-            // I will never debug by hand grids larger than 999 !
-            // Also note the separation space
-            let line_index_str: String = format!(" {:0>3} ", x);
+    /// Rotate the grid clockwise by `quarter_turns` times 90 degrees.
+    pub fn rotate_clockwise(&self, quarter_turns: usize) -> Self {
+        let (depth, width) = (self.depth, self.width);
+        match quarter_turns % 4 {
+            0 => self.clone(),
+            1 => Self::from_fn(width, depth, |c| {
+                self.at(depth - 1 - c.y as usize, c.x as usize)
+            }),
+            2 => Self::from_fn(depth, width, |c| {
+                self.at(depth - 1 - c.x as usize, width - 1 - c.y as usize)
+            }),
+            _ => Self::from_fn(width, depth, |c| {
+                self.at(c.y as usize, width - 1 - c.x as usize)
+            }),
+        }
+    }
 
-            result.push_str(&line_index_str);
+    /// Mirror the grid east-west, reversing each row.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.depth, self.width, |c| {
+            self.at(c.x as usize, self.width - 1 - c.y as usize)
+        })
+    }
 
-            for y in 0..self.width() {
-                let current_coord: Coordinates = Coordinates {
-                    x: x as i32,
-                    y: y as i32,
-                };
+    /// Mirror the grid north-south, reversing the order of the rows.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.depth, self.width, |c| {
+            self.at(self.depth - 1 - c.x as usize, c.y as usize)
+        })
+    }
 
-                match self.get_ref(&current_coord) {
-                    None => result.push(' '),
-                    Some(_) if marked_position == Some(current_coord) => result.push('@'),
-                    Some(val) => result.push_str(&format!("{val:?}")), // Better if this is 1 char long ....
-                }
-            }
-            result.push_str(&line_index_str);
-            result.push('\n');
-        }
+    /// Copy of the `depth` x `width` area whose north-west corner is `corner`.
+    ///
+    /// Returns `None` if the area does not fit in the grid.
+    pub fn sub_grid(&self, corner: &Coordinates, depth: usize, width: usize) -> Option<Self> {
+        let fits: bool = 0 <= corner.x
+            && 0 <= corner.y
+            && corner.x as usize + depth <= self.depth
+            && corner.y as usize + width <= self.width;
+
+        fits.then(|| {
+            Self::from_fn(depth, width, |c| {
+                self.at((corner.x + c.x) as usize, (corner.y + c.y) as usize)
+            })
+        })
+    }
+
+    /// Replace each cell by a `depth` x `width` block of its copies.
+    pub fn expand(&self, depth: usize, width: usize) -> Self {
+        self.expand_with(depth, width, |value| vec![value.clone(); depth * width])
+    }
+
+    /// Replace each cell by a `depth` x `width` block, given row after row by `block_of`.
+    pub fn expand_with<F: FnMut(&T) -> Vec<T>>(
+        &self,
+        depth: usize,
+        width: usize,
+        mut block_of: F,
+    ) -> Self {
+        let blocks: Vec<Vec<T>> = self
+            .data
+            .iter()
+            .map(|value| {
+                let block: Vec<T> = block_of(value);
+                assert_eq!(
+                    block.len(),
+                    depth * width,
+                    "Block of {depth}x{width} values"
+                );
+                block
+            })
+            .collect();
 
-        result.push_str(&horizontal_indexes);
+        Self::from_fn(self.depth * depth, self.width * width, |c| {
+            let (x, y) = (c.x as usize, c.y as usize);
+            let block: &[T] = &blocks[(x / depth) * self.width + y / width];
+            block[(x % depth) * width + y % width].clone()
+        })
+    }
 
-        println!("{result}");
+    /// Repeat the grid `depth` times vertically and `width` times horizontally.
+    pub fn tile(&self, depth: usize, width: usize) -> Self {
+        Self::from_fn(self.depth * depth, self.width * width, |c| {
+            self.at(c.x as usize % self.depth, c.y as usize % self.width)
+        })
     }
 }
@@ -5,10 +5,12 @@ pub mod grid;
 pub mod iterators;
 pub mod maths;
 pub mod parsing;
+pub mod render;
 
 // Re-exports
 pub use collection_hashmap::CollectionHashMap;
 pub use coordinates::Coordinates;
 pub use directions::Direction;
-pub use grid::Grid;
+pub use grid::{FromGridChar, Grid, Markers};
 pub use iterators::power;
+pub use render::{RenderTile, Rgb, Scene};
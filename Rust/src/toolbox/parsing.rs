@@ -1,11 +1,60 @@
+use nom::IResult;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::error::AocError;
+
 static NUMBERS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"-?\d+").unwrap());
 
 pub fn parse_numbers(line: &str) -> Vec<i32> {
     NUMBERS_REGEX
         .find_iter(line)
-        .map(|re_match| re_match.as_str().parse::<i32>().unwrap())
+        .filter_map(|re_match| re_match.as_str().parse::<i32>().ok())
         .collect()
 }
+
+/// Run a nom `parser` on a line, converting its failure into an [`AocError::Parse`].
+///
+/// `line_index` is 0-based, as given by `enumerate()`.
+pub fn parse_line_with<'a, O, P>(
+    line_index: usize,
+    line: &'a str,
+    mut parser: P,
+) -> Result<O, AocError>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    match parser(line) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => Err(AocError::parse(
+            line_index + 1,
+            line.len() - error.input.len() + 1,
+            format!("Unexpected input ({:?})", error.code),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse(
+            line_index + 1,
+            line.len() + 1,
+            "Unexpected end of line",
+        )),
+    }
+}
+
+/// Convert a byte offset in `input` into a 1-based (line, column) position.
+pub fn offset_to_position(input: &str, offset: usize) -> (usize, usize) {
+    let before: &str = &input[..offset];
+    let line: usize = before.matches('\n').count() + 1;
+    let column: usize = match before.rfind('\n') {
+        None => offset + 1,
+        Some(line_start) => offset - line_start,
+    };
+    (line, column)
+}
+
+/// Build the error raised when meeting an unexpected char in a grid-like input.
+pub fn unexpected_char(line_index: usize, column_index: usize, chr: char) -> AocError {
+    AocError::parse(
+        line_index + 1,
+        column_index + 1,
+        format!("Unexpected char '{chr}'"),
+    )
+}
@@ -0,0 +1,374 @@
+use std::fs;
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::error::AocError;
+use crate::toolbox::{Coordinates, Grid};
+
+/// Color of a cell, from 0 to 255 per channel.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const DARK_GREY: Rgb = Rgb(64, 64, 64);
+    pub const GREY: Rgb = Rgb(160, 160, 160);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(230, 190, 40);
+
+    /// Color between `self` (at 0) and `other` (at 1).
+    pub fn blend(self, other: Rgb, ratio: f64) -> Rgb {
+        let ratio: f64 = ratio.clamp(0.0, 1.0);
+        let mix =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * ratio).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// How a tile looks once rendered: a character in text, a color in images.
+pub trait RenderTile {
+    fn glyph(&self) -> char;
+
+    /// Color of the tile's cell in images, and of its glyph in terminals.
+    fn color(&self) -> Rgb {
+        Rgb::GREY
+    }
+}
+
+/// Walls and floors are drawn dark and light, other characters stand out.
+impl RenderTile for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            '#' => Rgb::DARK_GREY,
+            '.' => Rgb::WHITE,
+            _ => Rgb::BLUE,
+        }
+    }
+}
+
+/// Digits, from dark for 0 to light for 9.
+impl RenderTile for u32 {
+    fn glyph(&self) -> char {
+        char::from_digit(*self, 10).unwrap_or('+')
+    }
+
+    fn color(&self) -> Rgb {
+        Rgb::BLACK.blend(Rgb::WHITE, *self as f64 / 9.0)
+    }
+}
+
+/// Drawing added on top of the tiles.
+#[derive(Clone, Debug)]
+enum Overlay {
+    Cells {
+        cells: Vec<Coordinates>,
+        glyph: Option<char>, // The tile's own glyph is kept if `None`
+        color: Rgb,
+    },
+    /// Values scaled from their minimum to their maximum, shown as background colors.
+    Heatmap(Vec<(Coordinates, f64)>),
+}
+
+/// Appearance of a cell, once the overlays are applied.
+#[derive(Clone, Copy, Debug)]
+struct Cell {
+    glyph: char,
+    color: Rgb,
+    background: Option<Rgb>,
+}
+
+impl Cell {
+    /// Color filling the cell in images.
+    fn fill(&self) -> Rgb {
+        self.background.unwrap_or(self.color)
+    }
+}
+
+const HEAT_COLD: Rgb = Rgb::BLUE;
+const HEAT_HOT: Rgb = Rgb::RED;
+
+/// A grid and the overlays drawn on top of it, later ones hiding earlier ones.
+///
+/// ```ignore
+/// let text: String = Scene::new(&maze).path(&path, 'O').mark(start, 'S').to_ansi();
+/// ```
+pub struct Scene<'a, T> {
+    grid: &'a Grid<T>,
+    overlays: Vec<Overlay>,
+    with_indexes: bool,
+}
+
+impl<'a, T: RenderTile> Scene<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Scene {
+            grid,
+            overlays: Vec::new(),
+            with_indexes: false,
+        }
+    }
+
+    /// Draw cells with the given glyph and color.
+    pub fn cells(mut self, cells: &[Coordinates], glyph: Option<char>, color: Rgb) -> Self {
+        self.overlays.push(Overlay::Cells {
+            cells: cells.to_vec(),
+            glyph,
+            color,
+        });
+        self
+    }
+
+    /// Draw a path, in green.
+    pub fn path(self, cells: &[Coordinates], glyph: char) -> Self {
+        self.cells(cells, Some(glyph), Rgb::GREEN)
+    }
+
+    /// Draw a single cell, in red.
+    pub fn mark(self, cell: Coordinates, glyph: char) -> Self {
+        self.cells(&[cell], Some(glyph), Rgb::RED)
+    }
+
+    /// Color the background of cells from blue for the lowest value to red for the highest one.
+    pub fn heatmap(mut self, values: impl IntoIterator<Item = (Coordinates, f64)>) -> Self {
+        self.overlays
+            .push(Overlay::Heatmap(values.into_iter().collect()));
+        self
+    }
+
+    /// Surround text renderings with the indexes of the rows and columns.
+    pub fn with_indexes(mut self) -> Self {
+        self.with_indexes = true;
+        self
+    }
+
+    // Cells resolution
+
+    fn cells_grid(&self) -> Grid<Cell> {
+        let mut cells: Grid<Cell> = self.grid.map(|tile| Cell {
+            glyph: tile.glyph(),
+            color: tile.color(),
+            background: None,
+        });
+
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells {
+                    cells: positions,
+                    glyph,
+                    color,
+                } => {
+                    for pos in positions {
+                        if let Some(cell) = cells.get_mut(pos) {
+                            cell.glyph = glyph.unwrap_or(cell.glyph);
+                            cell.color = *color;
+                        }
+                    }
+                }
+                Overlay::Heatmap(values) => {
+                    let (min, max) = values.iter().fold(
+                        (f64::INFINITY, f64::NEG_INFINITY),
+                        |(min, max), (_, value)| (min.min(*value), max.max(*value)),
+                    );
+                    let range: f64 = (max - min).max(f64::EPSILON);
+                    for (pos, value) in values {
+                        if let Some(cell) = cells.get_mut(pos) {
+                            cell.background =
+                                Some(HEAT_COLD.blend(HEAT_HOT, (value - min) / range));
+                        }
+                    }
+                }
+            }
+        }
+
+        cells
+    }
+
+    // Text
+
+    /// Glyphs only, one line per row.
+    pub fn to_text(&self) -> String {
+        self.render_text(|cell| cell.glyph.to_string())
+    }
+
+    /// Glyphs colored with ANSI escape codes, for terminals.
+    pub fn to_ansi(&self) -> String {
+        self.render_text(|cell| {
+            let (Rgb(r, g, b), glyph) = (cell.color, cell.glyph.to_string());
+            match cell.background {
+                None => glyph.truecolor(r, g, b).to_string(),
+                Some(Rgb(br, bg, bb)) => glyph
+                    .truecolor(r, g, b)
+                    .on_truecolor(br, bg, bb)
+                    .to_string(),
+            }
+        })
+    }
+
+    fn render_text<F: Fn(&Cell) -> String>(&self, draw: F) -> String {
+        let cells: Grid<Cell> = self.cells_grid();
+
+        // Rows are numbered on both sides, columns by their last digit
+        let column_indexes: String = format!(
+            "     {}\n",
+            (0..cells.width())
+                .map(|y| char::from_digit((y % 10) as u32, 10).unwrap())
+                .collect::<String>()
+        );
+
+        let mut result: String = String::new();
+        if self.with_indexes {
+            result.push_str(&column_indexes);
+        }
+        for (x, row) in cells.rows().enumerate() {
+            let row_index: String = format!(" {x:0>3} ");
+            if self.with_indexes {
+                result.push_str(&row_index);
+            }
+            result.extend(row.iter().map(&draw));
+            if self.with_indexes {
+                result.push_str(&row_index);
+            }
+            result.push('\n');
+        }
+        if self.with_indexes {
+            result.push_str(&column_indexes);
+        }
+
+        result
+    }
+
+    // Images
+
+    /// Colors only, each cell being a square of `cell_size` pixels.
+    pub fn to_image(&self, cell_size: usize) -> Image {
+        let cells: Grid<Cell> = self.cells_grid();
+        let pixels: Vec<u8> = cells
+            .iter()
+            .flat_map(|cell| {
+                let Rgb(r, g, b) = cell.fill();
+                [r, g, b]
+            })
+            .collect();
+
+        Image {
+            width: cells.width(),
+            height: cells.depth(),
+            pixels,
+        }
+        .scaled(cell_size)
+    }
+
+    /// Colors as SVG rectangles, one per cell, scaling without blur unlike images.
+    pub fn to_svg(&self, cell_size: usize) -> String {
+        let cells: Grid<Cell> = self.cells_grid();
+        let size: usize = cell_size.max(1);
+
+        let mut svg: String = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+            cells.width() * size,
+            cells.depth() * size
+        );
+        for (pos, cell) in cells.enumerate() {
+            let (x, y) = (pos.y as usize * size, pos.x as usize * size);
+            svg.push_str(&format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"{}\"/>\n",
+                cell.fill().hex()
+            ));
+        }
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    /// Write the scene to a file, its format following the extension: `txt`, `ppm`, `png` or `svg`.
+    pub fn save(&self, path: &Path, cell_size: usize) -> Result<(), AocError> {
+        let extension: String = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let content: Vec<u8> = match extension.as_str() {
+            "txt" => self.to_text().into_bytes(),
+            "ppm" => self.to_image(cell_size).to_ppm(),
+            "png" => self
+                .to_image(cell_size)
+                .to_png()
+                .map_err(|message| AocError::render(path, message))?,
+            "svg" => self.to_svg(cell_size).into_bytes(),
+            _ => {
+                return Err(AocError::render(
+                    path,
+                    "Unknown format, expected txt, ppm, png or svg",
+                ))
+            }
+        };
+
+        fs::write(path, content).map_err(|error| AocError::render(path, error.to_string()))
+    }
+}
+
+/// Pixels of a rendered scene, as RGB triplets row after row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Same image, each pixel becoming a square of `factor` pixels.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let factor: usize = factor.max(1);
+        let mut pixels: Vec<u8> = Vec::with_capacity(self.pixels.len() * factor * factor);
+        for row in self.pixels.chunks(self.width.max(1) * 3) {
+            let line: Vec<u8> = row
+                .chunks(3)
+                .flat_map(|pixel| pixel.repeat(factor))
+                .collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        Image {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Binary PPM (P6), readable by most image viewers without any dependency.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm: Vec<u8> = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend_from_slice(&self.pixels);
+        ppm
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut png: Vec<u8> = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels))
+            .map_err(|error| error.to_string())?;
+        Ok(png)
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use colored::Colorize;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::aoc::{Aoc24Solution, InputSource};
+use crate::cli::{ExampleSelection, WatchArgs};
+use crate::error::AocError;
+use crate::registry::find_solution;
+use crate::runner::{print_day, run_days, select_inputs, DayReport, PartOutcome};
+
+/// Editors often write a file in several steps, which are gathered into a single change.
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Answers of a run, by input label and part.
+type Answers = BTreeMap<(String, usize), String>;
+
+/// Solve a day, then solve it again in-process whenever one of its input files changes, showing
+/// which answers changed.
+///
+/// Sources cannot be reloaded in-process: `on_sources_change` is called when a file of the day's
+/// module in `src_dir` changes, watching going on if it returns.
+pub fn watch_day(
+    args: &WatchArgs,
+    src_dir: &Path,
+    on_sources_change: &mut dyn FnMut(),
+) -> Result<(), AocError> {
+    let day: usize = args.day;
+    let source: InputSource = args.inputs.source();
+    let examples: Option<ExampleSelection> = args.inputs.examples();
+
+    let solve = || -> Vec<DayReport> {
+        let solution: Box<dyn Aoc24Solution> = find_solution(day).expect("The day has a solution");
+        let reports: Vec<DayReport> = run_days(
+            vec![solution],
+            &source,
+            examples.as_ref(),
+            &args.inputs.params,
+            args.part,
+            args.timeout,
+            1,
+        );
+        reports.iter().for_each(print_day);
+        reports
+    };
+
+    // The real input may be downloaded by the first run, which must not trigger another one
+    let mut answers: Answers = answers_of(&solve());
+
+    let solution: Box<dyn Aoc24Solution> = find_solution(day).expect("The day has a solution");
+    let inputs: Vec<PathBuf> = input_paths(solution.as_ref(), &source, examples.as_ref());
+    let sources: PathBuf = src_dir.join(format!("day{day:0>2}"));
+
+    let (sender, receiver) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(sender)
+        .map_err(|error| AocError::watch(&sources, error.to_string()))?;
+
+    // Files are often replaced rather than written, so their directory is watched instead
+    let input_dirs: BTreeSet<PathBuf> = inputs
+        .iter()
+        .filter_map(|path| path.parent().map(Path::to_path_buf))
+        .collect();
+    for dir in input_dirs.iter().filter(|dir| dir.is_dir()) {
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(|error| AocError::watch(dir, error.to_string()))?;
+    }
+    if sources.is_dir() {
+        watcher
+            .watch(&sources, RecursiveMode::Recursive)
+            .map_err(|error| AocError::watch(&sources, error.to_string()))?;
+    }
+
+    let inputs: Vec<PathBuf> = inputs.iter().map(|path| absolute(path)).collect();
+    let sources: PathBuf = absolute(&sources);
+    println!(
+        "\n{}",
+        format!("Watching day {day:0>2}, Ctrl-C to stop").dimmed()
+    );
+
+    loop {
+        let changed: Vec<PathBuf> = next_changes(&receiver)?;
+
+        if changed.iter().any(|path| path.starts_with(&sources)) {
+            on_sources_change();
+        } else if let Some(path) = changed.iter().find(|path| inputs.contains(path)) {
+            println!("\n{}", format!("{} changed", path.display()).dimmed());
+            let previous: Answers = std::mem::replace(&mut answers, answers_of(&solve()));
+            print_diff(&previous, &answers);
+        }
+    }
+}
+
+/// Files read by the selected inputs, none for stdin.
+fn input_paths(
+    solution: &dyn Aoc24Solution,
+    source: &InputSource,
+    examples: Option<&ExampleSelection>,
+) -> Vec<PathBuf> {
+    match source {
+        InputSource::DataDir(data_dir) => select_inputs(solution, examples)
+            .into_iter()
+            .map(|example| match example {
+                Some(name) => solution.build_test_path(data_dir, name),
+                None => solution.build_input_path(data_dir),
+            })
+            .collect(),
+        InputSource::File(path) => vec![path.clone()],
+        InputSource::Stdin => Vec::new(),
+    }
+}
+
+/// Wait for files to be changed, then gather the changes happening right after.
+fn next_changes(receiver: &Receiver<notify::Result<Event>>) -> Result<Vec<PathBuf>, AocError> {
+    let mut changed: Vec<PathBuf> = Vec::new();
+    let mut event = receiver.recv().ok();
+
+    while let Some(result) = event {
+        let change: Event = result.map_err(|error| {
+            let path: PathBuf = error.paths.first().cloned().unwrap_or_default();
+            AocError::watch(&path, error.to_string())
+        })?;
+        // Reading files must not trigger a run, only writing them
+        if !matches!(change.kind, EventKind::Access(_)) {
+            changed.extend(change.paths);
+        }
+
+        event = match changed.is_empty() {
+            true => receiver.recv().ok(),
+            false => receiver.recv_timeout(DEBOUNCE).ok(),
+        };
+    }
+
+    Ok(changed)
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    // The file itself may not exist yet, unlike its directory
+    match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => dir
+            .canonicalize()
+            .map(|dir| dir.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn answers_of(reports: &[DayReport]) -> Answers {
+    let mut answers: Answers = Answers::new();
+    for report in reports {
+        for (part, outcome) in [(1, &report.part_one), (2, &report.part_two)] {
+            if let PartOutcome::Solved(answer, _) = outcome {
+                answers.insert((report.label(), part), answer.to_string());
+            }
+        }
+    }
+    answers
+}
+
+/// Show the answers which differ from the previous run, if any.
+fn print_diff(previous: &Answers, current: &Answers) {
+    let keys: BTreeSet<&(String, usize)> = previous.keys().chain(current.keys()).collect();
+    let mut unchanged: bool = true;
+
+    for key @ (label, part) in keys {
+        let line: String = match (previous.get(key), current.get(key)) {
+            (Some(before), Some(after)) if before == after => continue,
+            (Some(before), Some(after)) => format!("{before} -> {after}"),
+            (None, Some(after)) => format!("none -> {after}"),
+            (Some(before), None) => format!("{before} -> none"),
+            (None, None) => continue,
+        };
+        println!("{}", format!("[Day {label}] Part {part}: {line}").yellow());
+        unchanged = false;
+    }
+
+    if unchanged {
+        println!("{}", "Answers unchanged".dimmed());
+    }
+}
@@ -0,0 +1,113 @@
+//! Regression tests over the examples given in the puzzles' statements.
+//!
+//! Each day declares its examples along with their expected answers, which are read from
+//! `tests/data/tests/`, laid out like a data directory: `--data-dir tests/data --test all`.
+
+use std::path::{Path, PathBuf};
+
+use aoc24::aoc::{Aoc24Solution, Example, InputSource, Params};
+
+use aoc24::day01::Day01;
+use aoc24::day02::Day02;
+use aoc24::day03::Day03;
+use aoc24::day04::Day04;
+use aoc24::day05::Day05;
+use aoc24::day06::Day06;
+use aoc24::day07::Day07;
+use aoc24::day08::Day08;
+use aoc24::day09::Day09;
+use aoc24::day10::Day10;
+use aoc24::day11::Day11;
+use aoc24::day12::Day12;
+use aoc24::day13::Day13;
+use aoc24::day14::Day14;
+use aoc24::day15::Day15;
+use aoc24::day16::Day16;
+use aoc24::day17::Day17;
+use aoc24::day18::Day18;
+use aoc24::day19::Day19;
+use aoc24::day20::Day20;
+use aoc24::day22::Day22;
+use aoc24::day23::Day23;
+use aoc24::day24::Day24;
+use aoc24::day25::Day25;
+
+/// Parse every example of a solution, then compare each part against its expected answer.
+fn check_examples(solution: &mut dyn Aoc24Solution) {
+    let data_dir: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("data");
+    let source: InputSource = InputSource::DataDir(data_dir.clone());
+
+    for example in solution.get_examples() {
+        check_example(solution, &source, &data_dir, &example);
+    }
+}
+
+fn check_example(
+    solution: &mut dyn Aoc24Solution,
+    source: &InputSource,
+    data_dir: &Path,
+    example: &Example,
+) {
+    let path: PathBuf = solution.build_test_path(data_dir, example.name);
+    let path = path.display();
+
+    let input: String = solution
+        .get_data(source, Some(example.name))
+        .unwrap_or_else(|error| panic!("{error}"));
+
+    if let Err(error) = solution.parse(&input) {
+        panic!("Failed to parse '{path}': {error}");
+    }
+    let params: Params = Params::resolve(&solution.get_params(), true, &[]).unwrap();
+
+    for (part, expected) in [(1, example.part_one), (2, example.part_two)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        match solution.solve_part(part, &params) {
+            Ok(answer) => assert_eq!(answer.to_string(), expected, "Part {part} of '{path}'"),
+            Err(error) => panic!("Part {part} of '{path}' failed: {error}"),
+        }
+    }
+}
+
+/// Generate one test per day, checking all of its examples.
+macro_rules! examples {
+    ($($name:ident: $solution:ty;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_examples(&mut <$solution>::default());
+            }
+        )*
+    };
+}
+
+examples! {
+    day01: Day01;
+    day02: Day02;
+    day03: Day03;
+    day04: Day04;
+    day05: Day05;
+    day06: Day06;
+    day07: Day07;
+    day08: Day08;
+    day09: Day09;
+    day10: Day10;
+    day11: Day11;
+    day12: Day12;
+    day13: Day13;
+    day14: Day14;
+    day15: Day15;
+    day16: Day16;
+    day17: Day17;
+    day18: Day18;
+    day19: Day19;
+    day20: Day20;
+    day22: Day22;
+    day23: Day23;
+    day24: Day24;
+    day25: Day25;
+}
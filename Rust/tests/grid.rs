@@ -0,0 +1,110 @@
+//! Geometric transforms and parsing of `toolbox::Grid`.
+
+use aoc24::error::AocError;
+use aoc24::toolbox::{Coordinates, Grid, Markers};
+
+/// 2x3 grid:
+/// ```text
+/// abc
+/// def
+/// ```
+fn letters() -> Grid<char> {
+    Grid::from_string("abc\ndef\n")
+}
+
+fn rows(grid: &Grid<char>) -> Vec<String> {
+    grid.rows().map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn rotations() {
+    let grid: Grid<char> = letters();
+
+    assert_eq!(rows(&grid.rotate_clockwise(0)), ["abc", "def"]);
+    assert_eq!(rows(&grid.rotate_clockwise(1)), ["da", "eb", "fc"]);
+    assert_eq!(rows(&grid.rotate_clockwise(2)), ["fed", "cba"]);
+    assert_eq!(rows(&grid.rotate_clockwise(3)), ["cf", "be", "ad"]);
+    assert_eq!(grid.rotate_clockwise(4), grid);
+}
+
+#[test]
+fn transpose_and_flips() {
+    let grid: Grid<char> = letters();
+
+    assert_eq!(rows(&grid.transpose()), ["ad", "be", "cf"]);
+    assert_eq!(rows(&grid.flip_horizontal()), ["cba", "fed"]);
+    assert_eq!(rows(&grid.flip_vertical()), ["def", "abc"]);
+}
+
+#[test]
+fn sub_grid() {
+    let grid: Grid<char> = letters();
+
+    let corner: Coordinates = Coordinates { x: 0, y: 1 };
+    assert_eq!(rows(&grid.sub_grid(&corner, 2, 2).unwrap()), ["bc", "ef"]);
+    assert_eq!(grid.sub_grid(&corner, 2, 3), None);
+    assert_eq!(grid.sub_grid(&Coordinates { x: -1, y: 0 }, 1, 1), None);
+}
+
+#[test]
+fn expand_and_tile() {
+    let grid: Grid<char> = Grid::from_string("ab\n");
+
+    assert_eq!(rows(&grid.expand(2, 2)), ["aabb", "aabb"]);
+    assert_eq!(
+        rows(&grid.expand_with(1, 2, |chr| vec![*chr, chr.to_ascii_uppercase()])),
+        ["aAbB"]
+    );
+    assert_eq!(rows(&grid.tile(2, 3)), ["ababab", "ababab"]);
+}
+
+#[test]
+fn parse_collects_markers() {
+    let (grid, markers): (Grid<char>, Markers) =
+        Grid::parse_with("S.#\n#.E\n", &['S', 'E'], Some).unwrap();
+
+    assert_eq!((grid.depth(), grid.width()), (2, 3));
+    assert_eq!(grid[Coordinates { x: 0, y: 2 }], '#');
+    assert_eq!(markers.single('S').unwrap(), Coordinates { x: 0, y: 0 });
+    assert_eq!(markers.single('E').unwrap(), Coordinates { x: 1, y: 2 });
+    assert!(markers.all('#').is_empty());
+}
+
+#[test]
+fn parse_reports_position_of_errors() {
+    let digits = |chr: char| chr.to_digit(10);
+
+    assert!(matches!(
+        Grid::parse_with("12\n3x\n", &[], digits),
+        Err(AocError::Parse {
+            line: 2,
+            column: 2,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Grid::parse_with("12\n3\n", &[], digits),
+        Err(AocError::Parse { line: 2, .. })
+    ));
+}
+
+#[test]
+fn rows_of_different_lengths() {
+    let grid: Grid<char> = letters();
+
+    assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+    assert_eq!(grid.row(2), None);
+    assert_eq!(
+        grid.column(2).map(|column| column.collect::<String>()),
+        Some(String::from("cf"))
+    );
+    assert!(grid.column(3).is_none());
+    assert!(matches!(
+        Grid::try_new(vec![vec![1, 2], vec![3], vec![4, 5]]),
+        Err(AocError::Parse {
+            line: 2,
+            column: 2,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,37 @@
+//! Comparison of the Rust and Python solutions, running the Python package.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc24::aoc::InputSource;
+use aoc24::day17::Day17;
+use aoc24::parity::{check_parity, default_python, ParityReport, PartParity, PythonSolutions};
+
+#[test]
+fn text_answers_are_compared() {
+    let input: PathBuf =
+        std::env::temp_dir().join(format!("aoc24-day17-{}.txt", std::process::id()));
+    fs::write(
+        &input,
+        "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n",
+    )
+    .unwrap();
+    let python: PythonSolutions = PythonSolutions::new(
+        &default_python(),
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("../Python3"),
+    );
+
+    let report: ParityReport = check_parity(
+        Box::new(Day17::default()),
+        &python,
+        &InputSource::File(input.clone()),
+        Some("1"),
+        &[],
+    );
+    fs::remove_file(&input).unwrap();
+
+    assert_eq!(
+        report.parts[0],
+        PartParity::Same(String::from("4,6,3,5,6,3,5,2,1,0"))
+    );
+}
@@ -0,0 +1,100 @@
+//! Input providers, the HTTP one running against a local mock server.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+
+use aoc24::error::AocError;
+use aoc24::provider::{CachedProvider, FsCache, HttpProvider, InputProvider};
+
+/// Serve a single request with the given status and body, returning the request's head.
+fn mock_server(status: &str, body: &str) -> (String, JoinHandle<Vec<String>>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").expect("Local port is free");
+    let base_url: String = format!("http://{}", listener.local_addr().unwrap());
+    let response: String = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("A request is sent");
+        let head: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map(|line| line.unwrap())
+            .take_while(|line| !line.is_empty())
+            .collect();
+        stream.write_all(response.as_bytes()).unwrap();
+        head
+    });
+
+    (base_url, handle)
+}
+
+fn temp_data_dir(name: &str) -> PathBuf {
+    let dir: PathBuf = std::env::temp_dir().join(format!("aoc24-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn http_sends_session_to_input_url() {
+    let (base_url, server) = mock_server("200 OK", "1 2\n3 4\n");
+    let provider: HttpProvider = HttpProvider::new(&base_url, "secret-token");
+
+    assert_eq!(provider.fetch(1).unwrap(), "1 2\n3 4\n");
+
+    let head: Vec<String> = server.join().unwrap();
+    assert_eq!(head[0], "GET /2024/day/1/input HTTP/1.1");
+    assert!(head.contains(&String::from("Cookie: session=secret-token")));
+}
+
+#[test]
+fn http_reports_status_without_session() {
+    let (base_url, server) = mock_server("404 Not Found", "Please don't repeatedly request this");
+    let provider: HttpProvider = HttpProvider::new(&base_url, "secret-token");
+
+    match provider.fetch(25) {
+        Err(error @ AocError::Download { .. }) => {
+            let message: String = error.to_string();
+            assert!(message.contains("HTTP 404"), "{message}");
+            assert!(!message.contains("secret-token"), "{message}");
+        }
+        other => panic!("Expected a download error, got {other:?}"),
+    }
+    server.join().unwrap();
+}
+
+#[test]
+fn cache_downloads_once() {
+    let data_dir: PathBuf = temp_data_dir("cache");
+    let (base_url, server) = mock_server("200 OK", "downloaded\n");
+    let provider = CachedProvider::new(
+        FsCache::new(&data_dir),
+        Some(Box::new(HttpProvider::new(&base_url, "token"))),
+    );
+
+    assert_eq!(provider.fetch(3).unwrap(), "downloaded\n");
+    server.join().unwrap();
+
+    // The server is gone, the input now comes from the cache
+    assert_eq!(provider.fetch(3).unwrap(), "downloaded\n");
+    assert_eq!(
+        fs::read_to_string(data_dir.join("inputs/day03.txt")).unwrap(),
+        "downloaded\n"
+    );
+
+    fs::remove_dir_all(&data_dir).unwrap();
+}
+
+#[test]
+fn cache_without_remote_reports_missing_input() {
+    let data_dir: PathBuf = temp_data_dir("offline");
+    let provider: CachedProvider = CachedProvider::new(FsCache::new(&data_dir), None);
+
+    assert!(matches!(
+        provider.fetch(4),
+        Err(AocError::MissingInput { .. })
+    ));
+}
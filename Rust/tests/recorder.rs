@@ -0,0 +1,71 @@
+//! Frames recorded by the solutions, then exported.
+//!
+//! The recorder is installed once per process, hence a single test.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use aoc24::cli::{FrameArgs, FrameFormat};
+use aoc24::record_frame;
+use aoc24::recorder::{self, Frame, FrameRecorder};
+use aoc24::toolbox::{Coordinates, Grid, Scene};
+
+#[test]
+fn record_then_export() {
+    let grid: Grid<char> = Grid::from_string("...\n");
+    let record_step = |y: i32| record_frame!(Scene::new(&grid).mark(Coordinates { x: 0, y }, '@'));
+
+    // Nothing is recorded before the recorder is installed, nor outside a sequence
+    recorder::set_sequence(Some(recorder::sequence_name(6, None, 1)));
+    record_step(0);
+    FrameRecorder::new(2).init();
+    recorder::set_sequence(None);
+    record_step(0);
+    assert!(!recorder::is_recording());
+
+    // Only the last frames are kept
+    recorder::set_sequence(Some(recorder::sequence_name(6, Some("small"), 1)));
+    (0..3).for_each(record_step);
+    let sequences: BTreeMap<String, Vec<Frame>> = recorder::take_sequences();
+    let texts: Vec<&str> = sequences["day06-small-part1"]
+        .iter()
+        .map(|frame| frame.text.as_str())
+        .collect();
+    assert_eq!(sequences.len(), 1);
+    assert_eq!(texts, [".@.\n", "..@\n"]);
+    // Frames are recorded a pixel per cell, then scaled like the scene itself
+    assert_eq!(
+        sequences["day06-small-part1"][1].image.scaled(2),
+        Scene::new(&grid)
+            .mark(Coordinates { x: 0, y: 2 }, '@')
+            .to_image(2)
+    );
+
+    let dir: PathBuf = std::env::temp_dir().join(format!("aoc24-frames-{}", std::process::id()));
+    let args: FrameArgs = FrameArgs {
+        dir: Some(dir.display().to_string()),
+        formats: vec![FrameFormat::Gif, FrameFormat::Png, FrameFormat::Text],
+        scale: 2,
+        delay: 100,
+        limit: 2,
+    };
+    let created: Vec<PathBuf> = recorder::export(&sequences, &dir, &args).unwrap();
+
+    assert_eq!(
+        created,
+        [
+            dir.join("day06-small-part1.gif"),
+            dir.join("day06-small-part1"),
+            dir.join("day06-small-part1.txt"),
+        ]
+    );
+    assert!(fs::read(&created[0]).unwrap().starts_with(b"GIF89a"));
+    assert!(created[1].join("frame-00002.png").is_file());
+    assert_eq!(
+        fs::read_to_string(&created[2]).unwrap(),
+        ".@.\n\u{c}\n..@\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
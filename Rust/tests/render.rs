@@ -0,0 +1,82 @@
+//! Rendering of grids and their overlays with `toolbox::render`.
+
+use std::path::Path;
+
+use aoc24::error::AocError;
+use aoc24::toolbox::render::Image;
+use aoc24::toolbox::{Coordinates, Grid, Rgb, Scene};
+
+/// 2x3 maze:
+/// ```text
+/// .#.
+/// ...
+/// ```
+fn maze() -> Grid<char> {
+    Grid::from_string(".#.\n...\n")
+}
+
+#[test]
+fn text_with_overlays() {
+    let grid: Grid<char> = maze();
+    let path: Vec<Coordinates> = vec![
+        Coordinates { x: 0, y: 0 },
+        Coordinates { x: 1, y: 0 },
+        Coordinates { x: 1, y: 1 },
+    ];
+
+    assert_eq!(Scene::new(&grid).to_text(), ".#.\n...\n");
+    assert_eq!(
+        Scene::new(&grid)
+            .path(&path, 'O')
+            .mark(Coordinates { x: 1, y: 1 }, '@')
+            .mark(Coordinates { x: 5, y: 5 }, '!') // Out of the grid
+            .to_text(),
+        "O#.\nO@.\n"
+    );
+    assert_eq!(
+        Scene::new(&grid).with_indexes().to_text(),
+        "     012\n 000 .#. 000 \n 001 ... 001 \n     012\n"
+    );
+}
+
+#[test]
+fn images() {
+    let grid: Grid<char> = maze();
+    let image: Image = Scene::new(&grid)
+        .heatmap([
+            (Coordinates { x: 1, y: 0 }, 0.0),
+            (Coordinates { x: 1, y: 2 }, 10.0),
+        ])
+        .to_image(2);
+
+    assert_eq!((image.width, image.height), (6, 4));
+    let pixel = |x: usize, y: usize| -> Rgb {
+        let index: usize = (x * image.width + y) * 3;
+        Rgb(
+            image.pixels[index],
+            image.pixels[index + 1],
+            image.pixels[index + 2],
+        )
+    };
+    assert_eq!(pixel(0, 0), Rgb::WHITE);
+    assert_eq!(pixel(1, 3), Rgb::DARK_GREY);
+    assert_eq!(pixel(3, 1), Rgb::BLUE);
+    assert_eq!(pixel(3, 5), Rgb::RED);
+
+    assert!(image.to_ppm().starts_with(b"P6\n6 4\n255\n"));
+    assert!(image.to_png().unwrap().starts_with(b"\x89PNG"));
+
+    let svg: String = Scene::new(&grid).to_svg(10);
+    assert!(svg.contains("width=\"30\" height=\"20\""));
+    assert_eq!(svg.matches("<rect").count(), 6);
+}
+
+#[test]
+fn save_requires_known_format() {
+    let grid: Grid<char> = maze();
+
+    assert!(matches!(
+        Scene::new(&grid).save(Path::new("maze.bmp"), 1),
+        Err(AocError::Render { .. })
+    ));
+}
@@ -0,0 +1,106 @@
+//! Running days in isolation from each other.
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc24::aoc::{Answer, Aoc24Solution, InputSource, Params};
+use aoc24::cli::PartSelection;
+use aoc24::error::AocError;
+use aoc24::runner::{run_days, DayReport, ParseOutcome, PartOutcome};
+
+/// Day whose first part answers at once, or never ends.
+struct Fake {
+    day: usize,
+    endless: bool,
+}
+
+impl Aoc24Solution for Fake {
+    fn get_day_number(&self) -> usize {
+        self.day
+    }
+
+    fn get_data(&self, _source: &InputSource, _example: Option<&str>) -> Result<String, AocError> {
+        Ok(String::new())
+    }
+
+    fn solve_part_one(&self, _params: &Params) -> Result<Answer, AocError> {
+        if self.endless {
+            loop {
+                thread::sleep(Duration::from_secs(60));
+            }
+        }
+        Ok(Answer::from(42))
+    }
+
+    fn solve_part_two(&self, _params: &Params) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// Day without any solution yet.
+struct Missing;
+
+impl Aoc24Solution for Missing {
+    fn get_day_number(&self) -> usize {
+        3
+    }
+
+    fn get_data(&self, _source: &InputSource, _example: Option<&str>) -> Result<String, AocError> {
+        Ok(String::new())
+    }
+}
+
+#[test]
+fn endless_day_does_not_block_the_next_ones() {
+    let timeout: Duration = Duration::from_millis(200);
+    let solutions: Vec<Box<dyn Aoc24Solution>> = vec![
+        Box::new(Fake {
+            day: 1,
+            endless: true,
+        }),
+        Box::new(Fake {
+            day: 2,
+            endless: false,
+        }),
+    ];
+    let source: InputSource = InputSource::File(PathBuf::from("unused.txt"));
+
+    let now: Instant = Instant::now();
+    let reports: Vec<DayReport> = run_days(
+        solutions,
+        &source,
+        None,
+        &[],
+        PartSelection::One,
+        Some(timeout),
+        1,
+    );
+
+    assert!(now.elapsed() < timeout * 5);
+    assert!(matches!(reports[0].part_one, PartOutcome::TimedOut(_)));
+    assert!(matches!(reports[1].parse, ParseOutcome::Parsed(_)));
+    assert!(matches!(
+        reports[1].part_one,
+        PartOutcome::Solved(Answer::Integer(42), _)
+    ));
+}
+
+#[test]
+fn unsolved_input_is_not_a_missing_part() {
+    let solutions: Vec<Box<dyn Aoc24Solution>> = vec![
+        Box::new(Fake {
+            day: 1,
+            endless: false,
+        }),
+        Box::new(Missing),
+    ];
+    let source: InputSource = InputSource::File(PathBuf::from("unused.txt"));
+
+    let reports: Vec<DayReport> =
+        run_days(solutions, &source, None, &[], PartSelection::Both, None, 1);
+
+    assert!(matches!(reports[0].part_two, PartOutcome::Unsolved));
+    assert!(matches!(reports[1].part_one, PartOutcome::NotImplemented));
+    assert!(reports.iter().all(|report| !report.is_failure()));
+}